@@ -9,6 +9,7 @@ export interface SyncConnectionsResponse {
   synced: number;
   platforms_created: number;
   platforms_updated: number;
+  platformsRemoved?: number;
 }
 
 export interface SyncAccountsResponse {
@@ -25,12 +26,20 @@ export interface SyncActivitiesResponse {
   accounts_failed: number;
 }
 
+export interface AccountSyncError {
+  accountId: string;
+  accountName: string;
+  error: string;
+}
+
 export interface SyncResult {
   success: boolean;
   message: string;
   connectionsSynced: SyncConnectionsResponse | null;
   accountsSynced: SyncAccountsResponse | null;
   activitiesSynced: SyncActivitiesResponse | null;
+  accountErrors: AccountSyncError[];
+  syncMode?: "FULL" | "ACTIVITIES_ONLY" | "HOLDINGS_ONLY";
 }
 
 export interface BrokerConnectionBrokerage {
@@ -47,6 +56,7 @@ export interface BrokerConnection {
   brokerage?: BrokerConnectionBrokerage;
   disabled?: boolean;
   disabled_date?: string;
+  disabled_reason?: string;
   updated_at?: string;
   status?: string;
   name?: string;
@@ -483,10 +483,10 @@ async fn perform_broker_activities_only_sync(
                 .upsert_account_activities(account_id.clone(), None, page.data)
                 .await
             {
-                Ok((upserted, assets, new_asset_ids, _needs_review)) => {
-                    account_upserted += upserted;
-                    account_assets += assets;
-                    account_new_asset_ids.extend(new_asset_ids);
+                Ok(upserted) => {
+                    account_upserted += upserted.activities_upserted;
+                    account_assets += upserted.assets_inserted;
+                    account_new_asset_ids.extend(upserted.new_asset_ids);
                 }
                 Err(err) => {
                     error!(
@@ -0,0 +1,94 @@
+//! Audit hook for writes performed during a broker sync.
+//!
+//! The orchestrator reports every successful write to an optional
+//! [`AuditLogger`], which can append the entries to a file or table.
+//! Failed writes are not audited; they surface as sync errors instead.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// Kind of record written during a sync.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuditEntity {
+    Connection,
+    /// Platform (brokerage) of a connection
+    Platform,
+    Account,
+    Activity,
+    /// Holdings snapshot of one account
+    Holdings,
+}
+
+/// Change applied to a record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AuditAction {
+    /// Created or updated; sync writes are upserts
+    Upsert,
+    /// Removed, e.g. the platform of a deleted connection
+    Delete,
+}
+
+/// One successful write performed by the orchestrator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEntry {
+    pub entity: AuditEntity,
+    /// Broker-side ID of the record (local account ID for holdings)
+    pub entity_id: String,
+    pub action: AuditAction,
+    pub timestamp: DateTime<Utc>,
+    /// Hex-encoded SHA-256 of the JSON payload that was written
+    /// (the record ID for deletes)
+    pub payload_hash: String,
+}
+
+impl AuditEntry {
+    /// Create an entry stamped with the current time.
+    pub fn new<T: Serialize>(
+        entity: AuditEntity,
+        entity_id: impl Into<String>,
+        action: AuditAction,
+        payload: &T,
+    ) -> Self {
+        Self {
+            entity,
+            entity_id: entity_id.into(),
+            action,
+            timestamp: Utc::now(),
+            payload_hash: payload_hash(payload),
+        }
+    }
+}
+
+/// SHA-256 of the JSON serialization of `payload`, hex-encoded.
+pub fn payload_hash<T: Serialize>(payload: &T) -> String {
+    let bytes = serde_json::to_vec(payload).unwrap_or_default();
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// Receives an entry for every successful sync write.
+pub trait AuditLogger: Send + Sync {
+    fn record(&self, entry: AuditEntry);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_hash_is_stable_and_content_sensitive() {
+        let hash = payload_hash(&serde_json::json!({ "id": "a1", "units": 10 }));
+        assert_eq!(hash.len(), 64);
+        assert_eq!(
+            hash,
+            payload_hash(&serde_json::json!({ "id": "a1", "units": 10 }))
+        );
+        assert_ne!(
+            hash,
+            payload_hash(&serde_json::json!({ "id": "a1", "units": 11 }))
+        );
+    }
+}
@@ -210,6 +210,26 @@ pub fn is_broker_crypto(code: Option<&str>) -> bool {
     )
 }
 
+/// Currency code an activity is denominated in, as reported by the broker.
+///
+/// Prefers the activity currency, then the symbol's native currency.
+/// Returns `None` when the broker reported neither.
+pub fn activity_currency_code(activity: &AccountUniversalActivity) -> Option<String> {
+    activity
+        .currency
+        .as_ref()
+        .and_then(|c| c.code.as_deref())
+        .or_else(|| {
+            activity
+                .symbol
+                .as_ref()
+                .and_then(|s| s.currency.as_ref())
+                .and_then(|c| c.code.as_deref())
+        })
+        .map(|c| c.trim().to_uppercase())
+        .filter(|c| !c.is_empty())
+}
+
 /// Maps a broker API activity into a `NewActivity` with unresolved `SymbolInput`.
 ///
 /// The returned `NewActivity` has `SymbolInput { symbol, exchange_mic, kind }` set
@@ -0,0 +1,137 @@
+//! Cumulative counters for broker sync operations.
+//!
+//! A [`SyncMetrics`] instance can be shared between orchestrators so that
+//! counters accumulate across sync runs, and snapshotted for exporters or
+//! debug views.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use serde::{Deserialize, Serialize};
+
+/// Thread-safe sync counters, incremented by the orchestrator.
+#[derive(Debug, Default)]
+pub struct SyncMetrics {
+    requests: AtomicU64,
+    activities_fetched: AtomicU64,
+    activities_imported: AtomicU64,
+    api_errors: AtomicU64,
+    persistence_errors: AtomicU64,
+    account_failures: AtomicU64,
+    run_failures: AtomicU64,
+}
+
+/// Point-in-time copy of [`SyncMetrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncMetricsSnapshot {
+    /// Broker API requests made
+    pub requests: u64,
+    /// Activities returned by the broker API
+    pub activities_fetched: u64,
+    /// Activities written to the local database
+    pub activities_imported: u64,
+    /// Broker API requests that failed
+    pub api_errors: u64,
+    /// Failed writes to the local database
+    pub persistence_errors: u64,
+    /// Accounts whose sync failed
+    pub account_failures: u64,
+    /// Sync runs that aborted
+    pub run_failures: u64,
+}
+
+impl SyncMetrics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Count a broker API request, and its failure if `result` is an error.
+    pub fn record_request<T, E>(&self, result: &Result<T, E>) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        if result.is_err() {
+            self.api_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Count a local write, and its failure if `result` is an error.
+    pub fn record_persistence<T, E>(&self, result: &Result<T, E>) {
+        if result.is_err() {
+            self.persistence_errors.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub fn add_activities_fetched(&self, count: usize) {
+        self.activities_fetched
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    pub fn add_activities_imported(&self, count: usize) {
+        self.activities_imported
+            .fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    pub fn record_account_failure(&self) {
+        self.account_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_run_failure(&self) {
+        self.run_failures.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Copy the current counter values.
+    pub fn snapshot(&self) -> SyncMetricsSnapshot {
+        SyncMetricsSnapshot {
+            requests: self.requests.load(Ordering::Relaxed),
+            activities_fetched: self.activities_fetched.load(Ordering::Relaxed),
+            activities_imported: self.activities_imported.load(Ordering::Relaxed),
+            api_errors: self.api_errors.load(Ordering::Relaxed),
+            persistence_errors: self.persistence_errors.load(Ordering::Relaxed),
+            account_failures: self.account_failures.load(Ordering::Relaxed),
+            run_failures: self.run_failures.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Reset all counters to zero.
+    pub fn reset(&self) {
+        for counter in [
+            &self.requests,
+            &self.activities_fetched,
+            &self.activities_imported,
+            &self.api_errors,
+            &self.persistence_errors,
+            &self.account_failures,
+            &self.run_failures,
+        ] {
+            counter.store(0, Ordering::Relaxed);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_request_counts_errors() {
+        let metrics = SyncMetrics::new();
+        metrics.record_request(&Ok::<(), ()>(()));
+        metrics.record_request(&Err::<(), ()>(()));
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.requests, 2);
+        assert_eq!(snapshot.api_errors, 1);
+    }
+
+    #[test]
+    fn test_reset_clears_counters() {
+        let metrics = SyncMetrics::new();
+        metrics.add_activities_fetched(10);
+        metrics.add_activities_imported(8);
+        metrics.record_persistence(&Err::<(), ()>(()));
+        metrics.record_account_failure();
+        metrics.record_run_failure();
+
+        metrics.reset();
+        assert_eq!(metrics.snapshot(), SyncMetricsSnapshot::default());
+    }
+}
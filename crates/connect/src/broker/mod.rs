@@ -1,12 +1,25 @@
+pub mod audit;
 pub mod mapping;
+pub mod metrics;
 mod models;
 pub mod orchestrator;
 pub mod progress;
+pub mod reconciliation;
 mod service;
 mod traits;
 
+#[cfg(test)]
+mod service_tests;
+
+pub use audit::{AuditAction, AuditEntity, AuditEntry, AuditLogger};
+pub use metrics::{SyncMetrics, SyncMetricsSnapshot};
 pub use models::*;
-pub use orchestrator::{SyncConfig, SyncOrchestrator};
+pub use orchestrator::{
+    ConfigError, InitialSyncStrategy, SyncConfig, SyncConfigBuilder, SyncOrchestrator,
+};
 pub use progress::{NoOpProgressReporter, SyncProgressPayload, SyncProgressReporter, SyncStatus};
+pub use reconciliation::{
+    reconcile_holdings, ComputedPosition, PositionDiscrepancy, ReconciliationReport,
+};
 pub use service::BrokerSyncService;
 pub use traits::*;
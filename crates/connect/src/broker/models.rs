@@ -1,7 +1,12 @@
 //! Models representing broker data from the cloud API.
 //! These models mirror Wealthfolio Connect API response structures.
 
+use std::collections::HashMap;
+
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use wealthfolio_core::accounts::TrackingMode;
 
 /// Broker account balance total (amount + currency)
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -170,6 +175,10 @@ pub struct BrokerConnection {
     /// When the connection was disabled
     pub disabled_date: Option<String>,
 
+    /// Why the connection was disabled (e.g., "authorization expired")
+    #[serde(default)]
+    pub disabled_reason: Option<String>,
+
     /// When the connection was last updated
     pub updated_at: Option<String>,
 
@@ -211,6 +220,29 @@ pub struct SyncConnectionsResponse {
     pub synced: usize,
     pub platforms_created: usize,
     pub platforms_updated: usize,
+    /// Platforms removed because their connection no longer exists
+    #[serde(default)]
+    pub platforms_removed: usize,
+    /// Connections whose platform was created or updated; internal to the
+    /// sync (audit), not sent to clients
+    #[serde(skip)]
+    pub synced_connection_ids: Vec<String>,
+    /// IDs of the removed platforms; internal to the sync (audit), not sent
+    /// to clients
+    #[serde(skip)]
+    pub removed_platform_ids: Vec<String>,
+}
+
+/// Result of upserting a batch of broker activities for a local account.
+#[derive(Debug, Clone, Default)]
+pub struct UpsertActivitiesResult {
+    pub activities_upserted: usize,
+    pub assets_inserted: usize,
+    pub new_asset_ids: Vec<String>,
+    pub needs_review_count: usize,
+    /// (broker activity ID, stored activity ID) of every activity created or
+    /// updated; user-modified activities are skipped and not listed
+    pub written_activity_ids: Vec<(String, String)>,
 }
 
 /// Pagination details from the broker API.
@@ -227,6 +259,33 @@ pub struct PaginationDetails {
     pub has_more: Option<bool>,
 }
 
+impl PaginationDetails {
+    /// Copy with negative values clamped to zero and `has_more` filled in.
+    ///
+    /// When the API omits `has_more`, it is derived from `total` if present
+    /// (`requested_offset + received < total`), otherwise from whether the page
+    /// came back full.
+    pub fn normalized(&self, requested_offset: i64, requested_limit: i64, received: usize) -> Self {
+        let offset = self.offset.map(|v| v.max(0));
+        let limit = self.limit.map(|v| v.max(0));
+        let total = self.total.map(|v| v.max(0));
+        let received = received as i64;
+
+        let has_more = self.has_more.unwrap_or_else(|| match (total, limit) {
+            (Some(total), _) => requested_offset.max(0) + received < total,
+            (None, Some(limit)) => received >= limit,
+            (None, None) => received >= requested_limit,
+        });
+
+        Self {
+            offset,
+            limit,
+            total,
+            has_more: Some(has_more),
+        }
+    }
+}
+
 /// A paginated list of universal activity objects.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct PaginatedUniversalActivity {
@@ -425,6 +484,21 @@ pub struct SyncActivitiesResponse {
     /// IDs of newly created assets (for background enrichment)
     #[serde(default)]
     pub new_asset_ids: Vec<String>,
+    /// Distinct activity currencies seen per synced account
+    #[serde(default)]
+    pub account_currencies: Vec<AccountCurrencySummary>,
+}
+
+/// Distinct currencies of the activities imported for one account.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountCurrencySummary {
+    /// Local account ID in wealthfolio
+    pub account_id: String,
+    /// Currency of the local account
+    pub account_currency: String,
+    /// Sorted, distinct currency codes reported on the imported activities
+    pub activity_currencies: Vec<String>,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -571,6 +645,30 @@ pub struct NewAccountInfo {
     pub institution_name: Option<String>,
 }
 
+/// Which kinds of account data a sync run fetches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SyncMode {
+    /// Activities for TRANSACTIONS accounts and holdings for HOLDINGS accounts
+    #[default]
+    Full,
+    /// Only activities for TRANSACTIONS accounts
+    ActivitiesOnly,
+    /// Only holdings for HOLDINGS accounts (no activity pagination)
+    HoldingsOnly,
+}
+
+impl SyncMode {
+    /// Whether accounts with the given tracking mode are synced in this mode.
+    pub fn includes(&self, tracking_mode: TrackingMode) -> bool {
+        match self {
+            SyncMode::Full => true,
+            SyncMode::ActivitiesOnly => tracking_mode == TrackingMode::Transactions,
+            SyncMode::HoldingsOnly => tracking_mode == TrackingMode::Holdings,
+        }
+    }
+}
+
 /// Combined result from a full broker sync operation.
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "camelCase")]
@@ -589,6 +687,182 @@ pub struct SyncResult {
     pub holdings_synced: Option<SyncHoldingsResponse>,
     /// List of newly created accounts that need tracking mode configuration
     pub new_accounts: Option<Vec<NewAccountInfo>>,
+    /// Per-account failures; a failed account does not abort the others
+    #[serde(default)]
+    pub account_errors: Vec<AccountSyncError>,
+    /// Mode the sync ran in
+    #[serde(default)]
+    pub sync_mode: SyncMode,
+}
+
+/// Upfront estimate of the work a broker sync would do.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SyncEstimate {
+    /// Number of accounts whose activities would be synced
+    pub accounts: usize,
+    /// Sum of the activity totals reported by the broker API
+    pub estimated_activities: u64,
+    /// Accounts for which the API did not report a total
+    pub accounts_without_total: usize,
+}
+
+/// An account whose data sync failed during a broker sync run.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountSyncError {
+    /// Local account ID in wealthfolio
+    pub account_id: String,
+    /// Human-readable account name
+    pub account_name: String,
+    /// Error message
+    pub error: String,
+}
+
+impl SyncActivitiesResponse {
+    /// Add another activities summary into this one.
+    pub fn merge(&mut self, other: SyncActivitiesResponse) {
+        self.accounts_synced += other.accounts_synced;
+        self.activities_upserted += other.activities_upserted;
+        self.assets_inserted += other.assets_inserted;
+        self.accounts_failed += other.accounts_failed;
+        self.new_asset_ids.extend(other.new_asset_ids);
+        self.account_currencies.extend(other.account_currencies);
+    }
+}
+
+impl SyncHoldingsResponse {
+    /// Add another holdings summary into this one.
+    pub fn merge(&mut self, other: SyncHoldingsResponse) {
+        self.accounts_synced += other.accounts_synced;
+        self.snapshots_upserted += other.snapshots_upserted;
+        self.positions_upserted += other.positions_upserted;
+        self.assets_inserted += other.assets_inserted;
+        self.accounts_failed += other.accounts_failed;
+        self.new_asset_ids.extend(other.new_asset_ids);
+    }
+}
+
+impl SyncResult {
+    /// Summarize this sync as a JSON blob users can share with support.
+    ///
+    /// Includes counts, per-account currency summaries and errors, but no
+    /// asset IDs. Credential-like query parameters in error messages are
+    /// redacted.
+    pub fn to_support_bundle(&self) -> serde_json::Value {
+        serde_json::json!({
+            "success": self.success,
+            "message": redact_secrets(&self.message),
+            "syncMode": self.sync_mode,
+            "connections": self.connections_synced.as_ref().map(|c| serde_json::json!({
+                "synced": c.synced,
+                "platformsCreated": c.platforms_created,
+                "platformsUpdated": c.platforms_updated,
+                "platformsRemoved": c.platforms_removed,
+            })),
+            "accounts": self.accounts_synced.as_ref().map(|a| serde_json::json!({
+                "synced": a.synced,
+                "created": a.created,
+                "updated": a.updated,
+                "skipped": a.skipped,
+            })),
+            "activities": self.activities_synced.as_ref().map(|a| serde_json::json!({
+                "accountsSynced": a.accounts_synced,
+                "accountsFailed": a.accounts_failed,
+                "activitiesUpserted": a.activities_upserted,
+                "assetsInserted": a.assets_inserted,
+                "accountCurrencies": a.account_currencies,
+            })),
+            "holdings": self.holdings_synced.as_ref().map(|h| serde_json::json!({
+                "accountsSynced": h.accounts_synced,
+                "accountsFailed": h.accounts_failed,
+                "positionsUpserted": h.positions_upserted,
+                "snapshotsUpserted": h.snapshots_upserted,
+                "assetsInserted": h.assets_inserted,
+            })),
+            "accountsNeedingSetup": self.new_accounts.as_ref().map_or(0, Vec::len),
+            "accountErrors": self
+                .account_errors
+                .iter()
+                .map(|e| serde_json::json!({
+                    "accountId": e.account_id,
+                    "accountName": e.account_name,
+                    "error": redact_secrets(&e.error),
+                }))
+                .collect::<Vec<_>>(),
+        })
+    }
+}
+
+/// Query parameters whose values are replaced by [`redact_secrets`].
+const SECRET_PARAMS: &[&str] = &["api_key=", "apikey=", "access_token=", "token=", "key="];
+
+/// Replace the values of credential-like query parameters (e.g. `api_key=...`).
+fn redact_secrets(text: &str) -> String {
+    let mut redacted = text.to_string();
+    for param in SECRET_PARAMS {
+        let mut search_from = 0;
+        while let Some(pos) = redacted[search_from..].to_ascii_lowercase().find(param) {
+            let value_start = search_from + pos + param.len();
+            let value_end = redacted[value_start..]
+                .find(|c: char| c == '&' || c == '"' || c == '\'' || c.is_whitespace())
+                .map_or(redacted.len(), |end| value_start + end);
+            redacted.replace_range(value_start..value_end, "[REDACTED]");
+            search_from = value_start + "[REDACTED]".len();
+        }
+    }
+    redacted
+}
+
+impl BrokerHoldingsResponse {
+    /// Total cash per currency code across all reported balances.
+    pub fn total_cash_by_currency(&self) -> HashMap<String, Decimal> {
+        total_cash_by_currency(self.balances.as_deref().unwrap_or_default())
+    }
+
+    /// Total market value (units x price) per currency code across all positions.
+    ///
+    /// Uses the position currency, falling back to the symbol's currency.
+    /// Positions without a currency, units or price are ignored.
+    pub fn total_market_value_by_currency(&self) -> HashMap<String, Decimal> {
+        let mut totals: HashMap<String, Decimal> = HashMap::new();
+        for position in self.positions.iter().flatten() {
+            let currency = position
+                .currency
+                .as_ref()
+                .and_then(|c| c.code.clone())
+                .or_else(|| {
+                    position
+                        .symbol
+                        .as_ref()
+                        .and_then(|s| s.symbol.as_ref())
+                        .and_then(|s| s.currency.as_ref())
+                        .and_then(|c| c.code.clone())
+                });
+            let units = position.units.and_then(Decimal::from_f64);
+            let price = position.price.and_then(Decimal::from_f64);
+            if let (Some(currency), Some(units), Some(price)) = (currency, units, price) {
+                *totals.entry(currency).or_insert(Decimal::ZERO) += units * price;
+            }
+        }
+        totals
+    }
+}
+
+/// Sum cash balances per currency code. Balances without a currency or cash
+/// amount are ignored.
+pub fn total_cash_by_currency(balances: &[HoldingsBalance]) -> HashMap<String, Decimal> {
+    let mut totals: HashMap<String, Decimal> = HashMap::new();
+    for balance in balances {
+        if let (Some(currency), Some(cash)) = (
+            balance.currency.as_ref().and_then(|c| c.code.clone()),
+            balance.cash,
+        ) {
+            let cash_decimal = Decimal::from_f64(cash).unwrap_or(Decimal::ZERO);
+            *totals.entry(currency).or_insert(Decimal::ZERO) += cash_decimal;
+        }
+    }
+    totals
 }
 
 impl BrokerAccount {
@@ -819,3 +1093,173 @@ pub struct UserInfo {
     pub team_role: Option<String>,
     pub team: Option<UserTeam>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn balance(currency: &str, cash: f64) -> HoldingsBalance {
+        HoldingsBalance {
+            currency: Some(HoldingsCurrency {
+                code: Some(currency.to_string()),
+                ..Default::default()
+            }),
+            cash: Some(cash),
+            buying_power: None,
+        }
+    }
+
+    #[test]
+    fn test_broker_connection_parses_disabled_reason() {
+        let connection: BrokerConnection = serde_json::from_str(
+            r#"{"id":"c1","disabled":true,"disabled_reason":"authorization expired"}"#,
+        )
+        .unwrap();
+        assert!(connection.disabled);
+        assert_eq!(
+            connection.disabled_reason.as_deref(),
+            Some("authorization expired")
+        );
+
+        let connection: BrokerConnection = serde_json::from_str(r#"{"id":"c2"}"#).unwrap();
+        assert_eq!(connection.disabled_reason, None);
+    }
+
+    #[test]
+    fn test_total_cash_by_currency_keeps_each_currency() {
+        let holdings = BrokerHoldingsResponse {
+            balances: Some(vec![
+                balance("TZS", 1_500_000.0),
+                balance("USD", 250.5),
+                balance("TZS", 500_000.0),
+            ]),
+            ..Default::default()
+        };
+
+        let totals = holdings.total_cash_by_currency();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["TZS"], Decimal::from(2_000_000));
+        assert_eq!(totals["USD"], Decimal::new(2505, 1));
+    }
+
+    #[test]
+    fn test_total_market_value_by_currency_sums_positions() {
+        let position = |currency: &str, units: f64, price: f64| HoldingsPosition {
+            units: Some(units),
+            price: Some(price),
+            currency: Some(HoldingsCurrency {
+                code: Some(currency.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let holdings = BrokerHoldingsResponse {
+            positions: Some(vec![
+                position("TZS", 100.0, 3200.0),
+                position("USD", 2.5, 10.1),
+                position("TZS", 10.0, 450.0),
+                HoldingsPosition {
+                    price: None,
+                    ..position("USD", 1.0, 1.0)
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let totals = holdings.total_market_value_by_currency();
+        assert_eq!(totals.len(), 2);
+        assert_eq!(totals["TZS"], Decimal::from(324_500));
+        assert_eq!(totals["USD"], Decimal::new(2525, 2));
+    }
+
+    #[test]
+    fn test_support_bundle_has_counts_and_no_api_key() {
+        let result = SyncResult {
+            success: false,
+            message: "Sync completed. 0 accounts created (1 failed).".to_string(),
+            activities_synced: Some(SyncActivitiesResponse {
+                accounts_synced: 1,
+                activities_upserted: 42,
+                accounts_failed: 1,
+                ..Default::default()
+            }),
+            account_errors: vec![AccountSyncError {
+                account_id: "acc".to_string(),
+                account_name: "Brokerage".to_string(),
+                error: "GET https://gateway.example/v1?api_key=s3cr3t&offset=0 failed".to_string(),
+            }],
+            ..Default::default()
+        };
+
+        let bundle = result.to_support_bundle();
+        for key in [
+            "success",
+            "message",
+            "syncMode",
+            "activities",
+            "accountErrors",
+        ] {
+            assert!(bundle.get(key).is_some(), "missing {}", key);
+        }
+        assert_eq!(bundle["activities"]["activitiesUpserted"], 42);
+
+        let text = bundle.to_string();
+        assert!(!text.contains("s3cr3t"));
+        assert!(text.contains("api_key=[REDACTED]&offset=0"));
+    }
+
+    #[test]
+    fn test_sync_connections_response_omits_internal_ids() {
+        let response = SyncConnectionsResponse {
+            synced: 1,
+            platforms_created: 1,
+            platforms_updated: 0,
+            platforms_removed: 0,
+            synced_connection_ids: vec!["c1".to_string()],
+            removed_platform_ids: vec!["QUESTRADE".to_string()],
+        };
+
+        let json = serde_json::to_value(&response).unwrap();
+
+        assert_eq!(json["platformsCreated"], 1);
+        assert!(json.get("syncedConnectionIds").is_none());
+        assert!(json.get("removedPlatformIds").is_none());
+    }
+
+    #[test]
+    fn test_pagination_derives_missing_has_more() {
+        let with_total = PaginationDetails {
+            total: Some(150),
+            ..Default::default()
+        };
+        assert_eq!(with_total.normalized(0, 100, 100).has_more, Some(true));
+        assert_eq!(with_total.normalized(100, 100, 50).has_more, Some(false));
+
+        let without_total = PaginationDetails::default();
+        assert_eq!(without_total.normalized(0, 100, 100).has_more, Some(true));
+        assert_eq!(without_total.normalized(0, 100, 40).has_more, Some(false));
+
+        let explicit = PaginationDetails {
+            total: Some(10),
+            has_more: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(explicit.normalized(0, 100, 10).has_more, Some(true));
+    }
+
+    #[test]
+    fn test_pagination_clamps_negative_values() {
+        let pagination = PaginationDetails {
+            offset: Some(-20),
+            limit: Some(-1),
+            total: Some(-5),
+            has_more: None,
+        };
+
+        let normalized = pagination.normalized(0, 100, 0);
+        assert_eq!(normalized.offset, Some(0));
+        assert_eq!(normalized.limit, Some(0));
+        assert_eq!(normalized.total, Some(0));
+        assert_eq!(normalized.has_more, Some(false));
+    }
+}
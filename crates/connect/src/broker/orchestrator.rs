@@ -3,24 +3,74 @@
 //! This module provides a unified sync implementation that can be used
 //! by both Tauri (desktop) and Axum (web) platforms.
 
-use std::collections::HashSet;
+use std::collections::{BTreeSet, HashSet};
 use std::sync::Arc;
 
-use log::{debug, error, info};
-
-use super::models::{NewAccountInfo, SyncActivitiesResponse, SyncHoldingsResponse, SyncResult};
+use chrono::Datelike;
+use futures::stream::{self, StreamExt};
+use log::{debug, error, info, warn};
+use serde::Serialize;
+use thiserror::Error;
+
+use super::audit::{AuditAction, AuditEntity, AuditEntry, AuditLogger};
+use super::mapping;
+use super::metrics::SyncMetrics;
+use super::models::{
+    AccountCurrencySummary, AccountSyncError, AccountUniversalActivity, BrokerAccount,
+    BrokerConnection, NewAccountInfo, SyncActivitiesResponse, SyncEstimate, SyncHoldingsResponse,
+    SyncMode, SyncResult,
+};
 use super::progress::{SyncProgressPayload, SyncProgressReporter, SyncStatus};
+use super::reconciliation::{reconcile_holdings, ComputedPosition, ReconciliationReport};
 use super::traits::{BrokerApiClient, BrokerSyncServiceTrait};
-use wealthfolio_core::accounts::TrackingMode;
-use wealthfolio_core::sync::{ImportRunMode, ImportRunStatus, ImportRunSummary};
+use wealthfolio_core::accounts::{Account, TrackingMode};
+use wealthfolio_core::sync::{
+    BrokerActivitiesCheckpoint, ImportRunMode, ImportRunStatus, ImportRunSummary,
+};
+
+/// Smallest activity page size accepted by [`SyncConfig`].
+pub const MIN_PAGE_LIMIT: i64 = 1;
+/// Largest activity page size accepted by [`SyncConfig`].
+pub const MAX_PAGE_LIMIT: i64 = 1000;
 
 /// Configuration for sync operations.
 #[derive(Debug, Clone)]
 pub struct SyncConfig {
     /// Number of activities to fetch per page.
+    /// Sent as the `limit` parameter and clamped to `MIN_PAGE_LIMIT..=MAX_PAGE_LIMIT`.
     pub page_limit: i64,
     /// Maximum number of pages to fetch per account (safety limit).
     pub max_pages: usize,
+    /// Maximum number of accounts synced in parallel (1 = sequential).
+    pub max_account_concurrency: usize,
+    /// How far back the first sync of an account reaches.
+    /// `None` fetches the full history.
+    pub default_lookback: Option<chrono::Duration>,
+    /// Maximum number of activities written per persistence call.
+    /// Larger pages are split into chunks of this size.
+    pub persistence_batch_size: usize,
+    /// Which account data to sync (activities, holdings or both).
+    pub sync_mode: SyncMode,
+    /// How the first sync of an account walks its history.
+    pub initial_sync_strategy: InitialSyncStrategy,
+    /// Largest quantity difference, in shares, that
+    /// [`SyncOrchestrator::reconcile_account_holdings`] treats as equal.
+    pub reconciliation_quantity_tolerance: rust_decimal::Decimal,
+    /// Largest average-cost difference, in the position currency, that
+    /// [`SyncOrchestrator::reconcile_account_holdings`] treats as equal.
+    pub reconciliation_cost_tolerance: rust_decimal::Decimal,
+}
+
+/// How the first activity sync of an account is performed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum InitialSyncStrategy {
+    /// Fetch the whole initial window in one paginated pass.
+    #[default]
+    AllAtOnce,
+    /// Fetch the initial window in calendar-month chunks, most recent first,
+    /// so recent activities are imported before older history.
+    /// Requires `default_lookback`, since a full-history window has no start date.
+    RecentFirstChunked,
 }
 
 impl Default for SyncConfig {
@@ -28,7 +78,268 @@ impl Default for SyncConfig {
         Self {
             page_limit: 1000,
             max_pages: 10_000,
+            max_account_concurrency: 1,
+            default_lookback: None,
+            persistence_batch_size: 500,
+            sync_mode: SyncMode::Full,
+            initial_sync_strategy: InitialSyncStrategy::AllAtOnce,
+            reconciliation_quantity_tolerance: rust_decimal::Decimal::new(1, 6),
+            reconciliation_cost_tolerance: rust_decimal::Decimal::new(1, 2),
+        }
+    }
+}
+
+impl SyncConfig {
+    /// Return the page limit clamped to the supported range, logging a warning
+    /// when the configured value is out of range.
+    pub fn clamped_page_limit(&self) -> i64 {
+        let clamped = self.page_limit.clamp(MIN_PAGE_LIMIT, MAX_PAGE_LIMIT);
+        if clamped != self.page_limit {
+            warn!(
+                "Activity page limit {} is out of range ({}..={}), using {}",
+                self.page_limit, MIN_PAGE_LIMIT, MAX_PAGE_LIMIT, clamped
+            );
+        }
+        clamped
+    }
+
+    /// Creates a validating builder seeded with the default settings.
+    pub fn builder() -> SyncConfigBuilder {
+        SyncConfigBuilder::default()
+    }
+}
+
+/// Errors returned when a [`SyncConfig`] fails validation.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ConfigError {
+    #[error(
+        "Page limit {0} is out of range ({min}..={max})",
+        min = MIN_PAGE_LIMIT,
+        max = MAX_PAGE_LIMIT
+    )]
+    PageLimitOutOfRange(i64),
+
+    #[error("Max pages must be greater than zero")]
+    ZeroMaxPages,
+
+    #[error("Max account concurrency must be greater than zero")]
+    ZeroAccountConcurrency,
+
+    #[error("Default lookback must not be negative")]
+    NegativeLookback,
+
+    #[error("Persistence batch size must be greater than zero")]
+    ZeroPersistenceBatchSize,
+
+    #[error("Chunked initial sync requires a default lookback")]
+    ChunkedSyncWithoutLookback,
+
+    #[error("Reconciliation tolerances must not be negative")]
+    NegativeReconciliationTolerance,
+}
+
+/// Builder for constructing a validated [`SyncConfig`].
+#[derive(Debug, Default)]
+pub struct SyncConfigBuilder {
+    config: SyncConfig,
+}
+
+impl SyncConfigBuilder {
+    pub fn page_limit(mut self, page_limit: i64) -> Self {
+        self.config.page_limit = page_limit;
+        self
+    }
+
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.config.max_pages = max_pages;
+        self
+    }
+
+    pub fn max_account_concurrency(mut self, concurrency: usize) -> Self {
+        self.config.max_account_concurrency = concurrency;
+        self
+    }
+
+    pub fn default_lookback(mut self, lookback: chrono::Duration) -> Self {
+        self.config.default_lookback = Some(lookback);
+        self
+    }
+
+    pub fn persistence_batch_size(mut self, batch_size: usize) -> Self {
+        self.config.persistence_batch_size = batch_size;
+        self
+    }
+
+    pub fn sync_mode(mut self, sync_mode: SyncMode) -> Self {
+        self.config.sync_mode = sync_mode;
+        self
+    }
+
+    pub fn initial_sync_strategy(mut self, strategy: InitialSyncStrategy) -> Self {
+        self.config.initial_sync_strategy = strategy;
+        self
+    }
+
+    pub fn reconciliation_quantity_tolerance(mut self, tolerance: rust_decimal::Decimal) -> Self {
+        self.config.reconciliation_quantity_tolerance = tolerance;
+        self
+    }
+
+    pub fn reconciliation_cost_tolerance(mut self, tolerance: rust_decimal::Decimal) -> Self {
+        self.config.reconciliation_cost_tolerance = tolerance;
+        self
+    }
+
+    /// Builds the SyncConfig, rejecting values the orchestrator would
+    /// otherwise have to clamp or could not make progress with.
+    pub fn build(self) -> Result<SyncConfig, ConfigError> {
+        let config = self.config;
+        if !(MIN_PAGE_LIMIT..=MAX_PAGE_LIMIT).contains(&config.page_limit) {
+            return Err(ConfigError::PageLimitOutOfRange(config.page_limit));
+        }
+        if config.max_pages == 0 {
+            return Err(ConfigError::ZeroMaxPages);
+        }
+        if config.max_account_concurrency == 0 {
+            return Err(ConfigError::ZeroAccountConcurrency);
+        }
+        if config
+            .default_lookback
+            .is_some_and(|lookback| lookback < chrono::Duration::zero())
+        {
+            return Err(ConfigError::NegativeLookback);
+        }
+        if config.persistence_batch_size == 0 {
+            return Err(ConfigError::ZeroPersistenceBatchSize);
+        }
+        if config.initial_sync_strategy == InitialSyncStrategy::RecentFirstChunked
+            && config.default_lookback.is_none()
+        {
+            return Err(ConfigError::ChunkedSyncWithoutLookback);
+        }
+        if config.reconciliation_quantity_tolerance.is_sign_negative()
+            || config.reconciliation_cost_tolerance.is_sign_negative()
+        {
+            return Err(ConfigError::NegativeReconciliationTolerance);
+        }
+        Ok(config)
+    }
+}
+
+/// Totals accumulated while paging through one account's activities.
+#[derive(Debug, Default)]
+struct ActivitySyncTotals {
+    fetched: u32,
+    inserted: u32,
+    assets_created: u32,
+    needs_review: u32,
+    new_asset_ids: Vec<String>,
+    /// Distinct currency codes reported on the fetched activities.
+    currencies: BTreeSet<String>,
+}
+
+/// Date range requested from the activities endpoint for one account.
+#[derive(Debug, PartialEq)]
+struct ActivityQueryWindow {
+    /// Start date (YYYY-MM-DD); `None` requests the full history
+    start_date: Option<String>,
+    /// End date (YYYY-MM-DD); `None` when no start date is set
+    end_date: Option<String>,
+    /// Whether a previous sync succeeded, making this an incremental run
+    incremental: bool,
+}
+
+/// Result of syncing one account's data, merged into the run summaries.
+enum AccountSyncOutcome {
+    Holdings(SyncHoldingsResponse),
+    Activities(SyncActivitiesResponse),
+    Failed {
+        /// Tracking mode of the failed account, used to pick the summary to count it in
+        kind: TrackingMode,
+        error: AccountSyncError,
+    },
+}
+
+impl ActivitySyncTotals {
+    /// Record the currencies of a page of activities.
+    fn record_currencies(&mut self, activities: &[AccountUniversalActivity]) {
+        self.currencies.extend(
+            activities
+                .iter()
+                .filter_map(mapping::activity_currency_code),
+        );
+    }
+
+    /// Add the totals of another window of the same account.
+    fn merge(&mut self, other: ActivitySyncTotals) {
+        self.fetched += other.fetched;
+        self.inserted += other.inserted;
+        self.assets_created += other.assets_created;
+        self.needs_review += other.needs_review;
+        self.new_asset_ids.extend(other.new_asset_ids);
+        self.currencies.extend(other.currencies);
+    }
+}
+
+/// Split `start..=end` into calendar-month windows, most recent first.
+/// The first and last windows are truncated to `end` and `start`.
+fn recent_first_monthly_windows(
+    start: chrono::NaiveDate,
+    end: chrono::NaiveDate,
+) -> Vec<(chrono::NaiveDate, chrono::NaiveDate)> {
+    let mut windows = Vec::new();
+    let mut window_end = end;
+    while window_end >= start {
+        let window_start = window_end.with_day(1).unwrap_or(window_end).max(start);
+        windows.push((window_start, window_end));
+        match window_start.pred_opt() {
+            Some(previous) => window_end = previous,
+            None => break,
+        }
+    }
+    windows
+}
+
+/// Parse a `%Y-%m-%d` window bound.
+fn parse_window_date(date: Option<&str>) -> Option<chrono::NaiveDate> {
+    date.and_then(|d| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok())
+}
+
+/// Add a window that was just synced to the completed range `(from, to)`.
+/// Windows that do not touch the range start a new one.
+fn extend_completed_range(
+    completed: Option<(chrono::NaiveDate, chrono::NaiveDate)>,
+    (start, end): (chrono::NaiveDate, chrono::NaiveDate),
+) -> (chrono::NaiveDate, chrono::NaiveDate) {
+    match completed {
+        Some((from, to))
+            if start <= to.succ_opt().unwrap_or(to) && end.succ_opt().unwrap_or(end) >= from =>
+        {
+            (from.min(start), to.max(end))
         }
+        _ => (start, end),
+    }
+}
+
+/// Offset to resume an interrupted activity sync of a window from.
+///
+/// Only a checkpoint recorded for the same query window (both bounds) is
+/// honoured; anything else starts from the first page, since the offset
+/// would point into a different result set.
+fn resume_offset(
+    checkpoint: Option<&BrokerActivitiesCheckpoint>,
+    start_date: Option<&str>,
+    end_date: Option<&str>,
+) -> i64 {
+    match checkpoint {
+        Some(c)
+            if c.pending_offset > 0
+                && c.start_date.as_deref() == start_date
+                && c.end_date.as_deref() == end_date =>
+        {
+            c.pending_offset
+        }
+        _ => 0,
     }
 }
 
@@ -52,6 +363,8 @@ pub struct SyncOrchestrator<P: SyncProgressReporter> {
     sync_service: Arc<dyn BrokerSyncServiceTrait>,
     progress_reporter: Arc<P>,
     config: SyncConfig,
+    metrics: Arc<SyncMetrics>,
+    audit_logger: Option<Arc<dyn AuditLogger>>,
 }
 
 impl<P: SyncProgressReporter> SyncOrchestrator<P> {
@@ -59,12 +372,87 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
     pub fn new(
         sync_service: Arc<dyn BrokerSyncServiceTrait>,
         progress_reporter: Arc<P>,
-        config: SyncConfig,
+        mut config: SyncConfig,
     ) -> Self {
+        config.page_limit = config.clamped_page_limit();
         Self {
             sync_service,
             progress_reporter,
             config,
+            metrics: Arc::new(SyncMetrics::new()),
+            audit_logger: None,
+        }
+    }
+
+    /// Record counters into a shared metrics instance, so they accumulate
+    /// across orchestrators.
+    pub fn with_metrics(mut self, metrics: Arc<SyncMetrics>) -> Self {
+        self.metrics = metrics;
+        self
+    }
+
+    /// Metrics recorded by this orchestrator.
+    pub fn metrics(&self) -> Arc<SyncMetrics> {
+        Arc::clone(&self.metrics)
+    }
+
+    /// Report every successful write to an audit logger.
+    pub fn with_audit_logger(mut self, audit_logger: Arc<dyn AuditLogger>) -> Self {
+        self.audit_logger = Some(audit_logger);
+        self
+    }
+
+    /// Audit entries for records that were just written, stamped with the
+    /// current time; empty when no audit logger is set, so payloads are only
+    /// hashed when needed.
+    fn audit_entries<T: Serialize>(
+        &self,
+        entity: AuditEntity,
+        action: AuditAction,
+        records: &[T],
+        entity_id: impl Fn(&T) -> String,
+    ) -> Vec<AuditEntry> {
+        if self.audit_logger.is_none() {
+            return Vec::new();
+        }
+        records
+            .iter()
+            .map(|record| AuditEntry::new(entity, entity_id(record), action, record))
+            .collect()
+    }
+
+    /// Audit entries for the activities of `chunk` the sync service wrote,
+    /// keyed by the stored activity ID.
+    fn activity_audit_entries(
+        &self,
+        chunk: &[AccountUniversalActivity],
+        written_ids: &[(String, String)],
+    ) -> Vec<AuditEntry> {
+        if self.audit_logger.is_none() {
+            return Vec::new();
+        }
+        written_ids
+            .iter()
+            .filter_map(|(source_id, stored_id)| {
+                let activity = chunk
+                    .iter()
+                    .find(|a| a.id.as_deref() == Some(source_id.as_str()))?;
+                Some(AuditEntry::new(
+                    AuditEntity::Activity,
+                    stored_id.clone(),
+                    AuditAction::Upsert,
+                    activity,
+                ))
+            })
+            .collect()
+    }
+
+    /// Pass the entries of a successful write to the audit logger.
+    fn record_audit(&self, entries: Vec<AuditEntry>) {
+        if let Some(logger) = &self.audit_logger {
+            for entry in entries {
+                logger.record(entry);
+            }
         }
     }
 
@@ -84,6 +472,7 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
                 self.progress_reporter.report_sync_complete(sync_result);
             }
             Err(err) => {
+                self.metrics.record_run_failure();
                 // Create a failed result to emit the error event
                 let failed_result = SyncResult {
                     success: false,
@@ -93,6 +482,8 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
                     activities_synced: None,
                     holdings_synced: None,
                     new_accounts: None,
+                    account_errors: Vec::new(),
+                    sync_mode: self.config.sync_mode,
                 };
                 self.progress_reporter.report_sync_complete(&failed_result);
             }
@@ -101,6 +492,156 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
         result
     }
 
+    /// Re-sync one local account (activities or holdings, per its tracking mode)
+    /// without touching connections or other accounts.
+    pub async fn sync_single_account(
+        &self,
+        api_client: &dyn BrokerApiClient,
+        account_id: &str,
+    ) -> Result<SyncResult, String> {
+        let account = self
+            .sync_service
+            .get_synced_accounts()
+            .map_err(|e| format!("Failed to get synced accounts: {}", e))?
+            .into_iter()
+            .find(|a| a.id == account_id)
+            .ok_or_else(|| format!("Account {} is not a synced broker account", account_id))?;
+
+        let broker_account_id = account
+            .provider_account_id
+            .clone()
+            .ok_or_else(|| format!("Account {} has no provider account ID", account_id))?;
+
+        if account.tracking_mode == TrackingMode::NotSet {
+            return Err(format!(
+                "Account '{}' needs a tracking mode before it can be synced",
+                account.name
+            ));
+        }
+
+        if !self.config.sync_mode.includes(account.tracking_mode) {
+            return Err(format!(
+                "Account '{}' is not synced in {:?} mode",
+                account.name, self.config.sync_mode
+            ));
+        }
+
+        info!("Starting single-account sync for '{}'", account.name);
+        self.progress_reporter.report_sync_start();
+
+        let end_date = chrono::Utc::now().date_naive();
+        let mut result = SyncResult {
+            sync_mode: self.config.sync_mode,
+            ..Default::default()
+        };
+        match self
+            .sync_single_account_data(api_client, account, broker_account_id, end_date)
+            .await
+        {
+            AccountSyncOutcome::Holdings(holdings) => {
+                result.success = true;
+                result.message = format!(
+                    "Sync completed. {} holdings synced.",
+                    holdings.positions_upserted
+                );
+                result.holdings_synced = Some(holdings);
+            }
+            AccountSyncOutcome::Activities(activities) => {
+                result.success = true;
+                result.message = format!(
+                    "Sync completed. {} activities synced.",
+                    activities.activities_upserted
+                );
+                result.activities_synced = Some(activities);
+            }
+            AccountSyncOutcome::Failed { kind, error } => {
+                self.metrics.record_account_failure();
+                result.message = format!("Sync failed: {}", error.error);
+                match kind {
+                    TrackingMode::Holdings => {
+                        result.holdings_synced = Some(SyncHoldingsResponse {
+                            accounts_failed: 1,
+                            ..Default::default()
+                        })
+                    }
+                    _ => {
+                        result.activities_synced = Some(SyncActivitiesResponse {
+                            accounts_failed: 1,
+                            ..Default::default()
+                        })
+                    }
+                }
+                result.account_errors.push(error);
+            }
+        }
+
+        self.progress_reporter.report_sync_complete(&result);
+        Ok(result)
+    }
+
+    /// Estimate how many activities a sync would fetch, without importing anything.
+    ///
+    /// Requests a single-item page per sync-enabled TRANSACTIONS account and sums
+    /// the totals reported by the API for each account's query window.
+    pub async fn estimate_sync(
+        &self,
+        api_client: &dyn BrokerApiClient,
+    ) -> Result<SyncEstimate, String> {
+        let broker_accounts = api_client.list_accounts(None).await;
+        self.metrics.record_request(&broker_accounts);
+        let sync_enabled_broker_ids: HashSet<String> = broker_accounts
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter(|a| a.sync_enabled)
+            .filter_map(|a| a.id)
+            .collect();
+
+        let synced_accounts = self
+            .sync_service
+            .get_synced_accounts()
+            .map_err(|e| format!("Failed to get synced accounts: {}", e))?;
+
+        let end_date = chrono::Utc::now().date_naive();
+        let mut estimate = SyncEstimate::default();
+
+        for account in synced_accounts {
+            let Some(broker_account_id) = account.provider_account_id.as_deref() else {
+                continue;
+            };
+            if account.tracking_mode != TrackingMode::Transactions
+                || !self.config.sync_mode.includes(account.tracking_mode)
+                || !sync_enabled_broker_ids.contains(broker_account_id)
+            {
+                continue;
+            }
+
+            let window = self.compute_activity_query_window(&account.id, end_date)?;
+            let page = api_client
+                .get_account_activities(
+                    broker_account_id,
+                    window.start_date.as_deref(),
+                    window.end_date.as_deref(),
+                    Some(0),
+                    Some(1),
+                )
+                .await;
+            self.metrics.record_request(&page);
+            let page = page.map_err(|e| e.to_string())?;
+
+            estimate.accounts += 1;
+            match page.pagination.and_then(|p| p.total) {
+                Some(total) => estimate.estimated_activities += total.max(0) as u64,
+                None => estimate.accounts_without_total += 1,
+            }
+        }
+
+        info!(
+            "Sync estimate: ~{} activities across {} accounts",
+            estimate.estimated_activities, estimate.accounts
+        );
+        Ok(estimate)
+    }
+
     /// Internal sync logic that may fail at any step.
     async fn sync_all_internal(
         &self,
@@ -108,17 +649,34 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
     ) -> Result<SyncResult, String> {
         // Step 1: Sync connections (platforms)
         info!("Fetching broker connections...");
-        let connections = api_client
-            .list_connections()
-            .await
-            .map_err(|e| e.to_string())?;
+        let connections = api_client.list_connections().await;
+        self.metrics.record_request(&connections);
+        let connections = connections.map_err(|e| e.to_string())?;
         info!("Found {} broker connections", connections.len());
 
         let connections_result = self
             .sync_service
             .sync_connections(connections.clone())
-            .await
-            .map_err(|e| format!("Failed to sync connections: {}", e))?;
+            .await;
+        self.metrics.record_persistence(&connections_result);
+        let connections_result =
+            connections_result.map_err(|e| format!("Failed to sync connections: {}", e))?;
+        let synced_connections: Vec<&BrokerConnection> = connections
+            .iter()
+            .filter(|c| connections_result.synced_connection_ids.contains(&c.id))
+            .collect();
+        self.record_audit(self.audit_entries(
+            AuditEntity::Connection,
+            AuditAction::Upsert,
+            &synced_connections,
+            |c| c.id.clone(),
+        ));
+        self.record_audit(self.audit_entries(
+            AuditEntity::Platform,
+            AuditAction::Delete,
+            &connections_result.removed_platform_ids,
+            |id| id.clone(),
+        ));
 
         info!(
             "Connections synced: {} created, {} updated",
@@ -134,8 +692,9 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
             } else {
                 Some(authorization_ids)
             })
-            .await
-            .map_err(|e| e.to_string())?;
+            .await;
+        self.metrics.record_request(&all_accounts);
+        let all_accounts = all_accounts.map_err(|e| e.to_string())?;
 
         info!(
             "Fetched {} total broker accounts from API",
@@ -166,11 +725,26 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
 
         info!("Syncing {} sync-enabled broker accounts", accounts.len());
 
-        let accounts_result = self
-            .sync_service
-            .sync_accounts(accounts)
-            .await
-            .map_err(|e| format!("Failed to sync accounts: {}", e))?;
+        let accounts_result = self.sync_service.sync_accounts(accounts.clone()).await;
+        self.metrics.record_persistence(&accounts_result);
+        let accounts_result =
+            accounts_result.map_err(|e| format!("Failed to sync accounts: {}", e))?;
+        // Existing accounts are skipped by the service, so only created ones are audited
+        let created_accounts: Vec<&BrokerAccount> = accounts
+            .iter()
+            .filter(|a| {
+                accounts_result
+                    .new_accounts_info
+                    .iter()
+                    .any(|info| a.id.as_ref() == Some(&info.provider_account_id))
+            })
+            .collect();
+        self.record_audit(self.audit_entries(
+            AuditEntity::Account,
+            AuditAction::Upsert,
+            &created_accounts,
+            |a| a.id.clone().unwrap_or_default(),
+        ));
 
         info!(
             "Accounts synced: {} created, {} updated, {} skipped",
@@ -184,7 +758,7 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
         // - TRANSACTIONS mode: sync activities
         // - HOLDINGS mode: sync holdings (positions)
         // - NOT_SET mode: skip (needs user configuration first)
-        let (activities_result, holdings_result) = self
+        let (activities_result, holdings_result, account_errors) = self
             .sync_account_data(api_client, &sync_enabled_broker_ids, &new_accounts_info)
             .await?;
 
@@ -236,6 +810,8 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
             activities_synced: Some(activities_result),
             holdings_synced: Some(holdings_result),
             new_accounts,
+            account_errors,
+            sync_mode: self.config.sync_mode,
         };
 
         Ok(result)
@@ -245,12 +821,22 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
     /// - TRANSACTIONS mode: sync activities
     /// - HOLDINGS mode: sync holdings (positions)
     /// - NOT_SET mode: skip (needs user configuration first)
+    ///
+    /// Up to `max_account_concurrency` accounts are synced at once. A failure on one
+    /// account is recorded in the returned errors and does not abort the others.
     async fn sync_account_data(
         &self,
         api_client: &dyn BrokerApiClient,
         sync_enabled_broker_ids: &HashSet<String>,
         new_accounts_info: &[NewAccountInfo],
-    ) -> Result<(SyncActivitiesResponse, SyncHoldingsResponse), String> {
+    ) -> Result<
+        (
+            SyncActivitiesResponse,
+            SyncHoldingsResponse,
+            Vec<AccountSyncError>,
+        ),
+        String,
+    > {
         let end_date = chrono::Utc::now().date_naive();
 
         // Build a set of newly created account IDs to skip them (they have trackingMode=NOT_SET)
@@ -266,255 +852,388 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
 
         let mut activities_summary = SyncActivitiesResponse::default();
         let mut holdings_summary = SyncHoldingsResponse::default();
+        let mut account_errors: Vec<AccountSyncError> = Vec::new();
 
-        for account in synced_accounts {
-            let Some(broker_account_id) = account.provider_account_id.clone() else {
-                continue;
-            };
-
-            // Skip accounts that are not sync-enabled
-            if !sync_enabled_broker_ids.contains(&broker_account_id) {
-                info!(
-                    "Skipping sync for account '{}' (sync disabled)",
-                    account.name
-                );
-                continue;
-            }
-
-            // Skip newly created accounts - they have trackingMode=NOT_SET and need user configuration
-            if new_account_ids.contains(&account.id) {
-                info!(
-                    "Skipping sync for new account '{}' (trackingMode=NOT_SET, needs user configuration)",
-                    account.name
-                );
-                continue;
-            }
+        let eligible_accounts: Vec<(Account, String)> = synced_accounts
+            .into_iter()
+            .filter_map(|account| {
+                let broker_account_id = account.provider_account_id.clone()?;
+                self.should_sync_account(
+                    &account,
+                    &broker_account_id,
+                    sync_enabled_broker_ids,
+                    &new_account_ids,
+                )
+                .then_some((account, broker_account_id))
+            })
+            .collect();
 
-            // Check tracking mode to determine sync type
-            match account.tracking_mode {
-                TrackingMode::NotSet => {
-                    info!(
-                        "Skipping sync for account '{}' (trackingMode=NOT_SET)",
-                        account.name
-                    );
-                    continue;
-                }
-                TrackingMode::Holdings => {
-                    // Sync holdings for HOLDINGS mode accounts
-                    match self
-                        .sync_account_holdings(
-                            api_client,
-                            &account.id,
-                            &account.name,
-                            &broker_account_id,
-                        )
-                        .await
-                    {
-                        Ok((positions_saved, assets_created, new_asset_ids)) => {
-                            holdings_summary.accounts_synced += 1;
-                            holdings_summary.positions_upserted += positions_saved;
-                            holdings_summary.snapshots_upserted += 1;
-                            holdings_summary.assets_inserted += assets_created;
-                            holdings_summary.new_asset_ids.extend(new_asset_ids);
-                        }
-                        Err(err) => {
-                            error!("Failed to sync holdings for '{}': {}", account.name, err);
-                            holdings_summary.accounts_failed += 1;
-                        }
+        let mut outcomes = stream::iter(eligible_accounts)
+            .map(|(account, broker_account_id)| {
+                self.sync_single_account_data(api_client, account, broker_account_id, end_date)
+            })
+            .buffer_unordered(self.config.max_account_concurrency.max(1));
+
+        while let Some(outcome) = outcomes.next().await {
+            match outcome {
+                AccountSyncOutcome::Holdings(result) => holdings_summary.merge(result),
+                AccountSyncOutcome::Activities(result) => activities_summary.merge(result),
+                AccountSyncOutcome::Failed { kind, error } => {
+                    self.metrics.record_account_failure();
+                    match kind {
+                        TrackingMode::Holdings => holdings_summary.accounts_failed += 1,
+                        _ => activities_summary.accounts_failed += 1,
                     }
-                    continue;
-                }
-                TrackingMode::Transactions => {
-                    // Continue with activity sync below
+                    account_errors.push(error);
                 }
             }
+        }
 
-            let account_id = account.id.clone();
-            let account_name = account.name.clone();
+        Ok((activities_summary, holdings_summary, account_errors))
+    }
 
-            // Mark sync attempt
-            if let Err(err) = self
-                .sync_service
-                .mark_activity_sync_attempt(account_id.clone())
-                .await
-                .map_err(|e| format!("Failed to mark activity sync attempt: {}", e))
-            {
-                error!(
-                    "Failed to mark activity sync attempt for '{}': {}",
-                    account_name, err
-                );
-                activities_summary.accounts_failed += 1;
-                continue;
-            }
+    /// Whether an account should take part in data sync, logging the reason when skipped.
+    fn should_sync_account(
+        &self,
+        account: &Account,
+        broker_account_id: &str,
+        sync_enabled_broker_ids: &HashSet<String>,
+        new_account_ids: &HashSet<String>,
+    ) -> bool {
+        // Skip accounts that are not sync-enabled
+        if !sync_enabled_broker_ids.contains(broker_account_id) {
+            info!(
+                "Skipping sync for account '{}' (sync disabled)",
+                account.name
+            );
+            return false;
+        }
 
-            // Compute query window
-            let (start_date, end_date_filter) =
-                self.compute_activity_query_window(&account_id, end_date)?;
+        // Skip newly created accounts - they have trackingMode=NOT_SET and need user configuration
+        if new_account_ids.contains(&account.id) {
+            info!(
+                "Skipping sync for new account '{}' (trackingMode=NOT_SET, needs user configuration)",
+                account.name
+            );
+            return false;
+        }
 
-            // Determine import run mode
-            let import_mode = if start_date.is_none() {
-                ImportRunMode::Initial
-            } else {
-                ImportRunMode::Incremental
-            };
+        if account.tracking_mode == TrackingMode::NotSet {
+            info!(
+                "Skipping sync for account '{}' (trackingMode=NOT_SET)",
+                account.name
+            );
+            return false;
+        }
+
+        if !self.config.sync_mode.includes(account.tracking_mode) {
+            debug!(
+                "Skipping sync for account '{}' ({:?} mode)",
+                account.name, self.config.sync_mode
+            );
+            return false;
+        }
+
+        true
+    }
+
+    /// Sync data for one account according to its tracking mode.
+    async fn sync_single_account_data(
+        &self,
+        api_client: &dyn BrokerApiClient,
+        account: Account,
+        broker_account_id: String,
+        end_date: chrono::NaiveDate,
+    ) -> AccountSyncOutcome {
+        let failed = |kind: TrackingMode, err: String| AccountSyncOutcome::Failed {
+            kind,
+            error: AccountSyncError {
+                account_id: account.id.clone(),
+                account_name: account.name.clone(),
+                error: err,
+            },
+        };
 
-            // Create import run
-            let import_run = match self
-                .sync_service
-                .create_import_run(&account_id, import_mode)
+        if account.tracking_mode == TrackingMode::Holdings {
+            // Sync holdings for HOLDINGS mode accounts
+            return match self
+                .sync_account_holdings(api_client, &account.id, &account.name, &broker_account_id)
                 .await
             {
-                Ok(run) => {
-                    debug!(
-                        "Created import run {} for account '{}'",
-                        run.id, account_name
-                    );
-                    Some(run)
+                Ok((positions_saved, assets_created, new_asset_ids)) => {
+                    AccountSyncOutcome::Holdings(SyncHoldingsResponse {
+                        accounts_synced: 1,
+                        positions_upserted: positions_saved,
+                        snapshots_upserted: 1,
+                        assets_inserted: assets_created,
+                        new_asset_ids,
+                        ..Default::default()
+                    })
                 }
-                Err(e) => {
-                    error!("Failed to create import run for '{}': {}", account_name, e);
-                    None
+                Err(err) => {
+                    error!("Failed to sync holdings for '{}': {}", account.name, err);
+                    failed(TrackingMode::Holdings, err)
                 }
             };
-            let import_run_id = import_run.as_ref().map(|r| r.id.clone());
+        }
 
-            let window_label = match (&start_date, &end_date_filter) {
-                (Some(s), Some(e)) => format!("{} -> {}", s, e),
-                _ => "ALL".to_string(),
-            };
-            info!(
-                "Syncing activities for account '{}' ({}): {}",
-                account_name, broker_account_id, window_label
-            );
+        let account_id = account.id.clone();
+        let account_name = account.name.clone();
 
-            // Emit sync start event
-            self.progress_reporter.report_progress(
-                SyncProgressPayload::new(&account_id, &account_name, SyncStatus::Syncing)
-                    .with_message(format!("Starting sync: {}", window_label)),
+        // Mark sync attempt
+        if let Err(err) = self
+            .sync_service
+            .mark_activity_sync_attempt(account_id.clone())
+            .await
+            .map_err(|e| format!("Failed to mark activity sync attempt: {}", e))
+        {
+            error!(
+                "Failed to mark activity sync attempt for '{}': {}",
+                account_name, err
             );
+            return failed(TrackingMode::Transactions, err);
+        }
 
-            // Sync activities with pagination
-            match self
-                .sync_account_activities(
-                    api_client,
-                    &account_id,
-                    &account_name,
-                    &broker_account_id,
-                    start_date.as_deref(),
-                    end_date_filter.as_deref(),
-                    import_run_id.clone(),
-                )
-                .await
-            {
-                Ok((fetched, inserted, assets_created, needs_review, new_asset_ids)) => {
-                    // Build import run summary first (needed for both success and failure paths)
-                    let summary = ImportRunSummary {
-                        fetched,
-                        inserted,
-                        updated: 0,
-                        skipped: 0,
-                        warnings: needs_review,
-                        errors: 0,
-                        removed: 0,
-                        assets_created,
-                    };
-
-                    // Finalize sync success (updates broker_sync_state table)
-                    let last_synced_date = end_date.format("%Y-%m-%d").to_string();
-                    let sync_state_failed = self
-                        .sync_service
-                        .finalize_activity_sync_success(
-                            account_id.clone(),
-                            last_synced_date,
-                            import_run_id.clone(),
-                        )
-                        .await
-                        .is_err();
+        // Compute query window
+        let window = match self.compute_activity_query_window(&account_id, end_date) {
+            Ok(window) => window,
+            Err(err) => {
+                error!(
+                    "Failed to compute query window for '{}': {}",
+                    account_name, err
+                );
+                return failed(TrackingMode::Transactions, err);
+            }
+        };
+        let windows = self.activity_windows(&window);
+        let ActivityQueryWindow {
+            start_date,
+            end_date: end_date_filter,
+            incremental,
+        } = window;
+
+        // Determine import run mode
+        let import_mode = if incremental {
+            ImportRunMode::Incremental
+        } else {
+            ImportRunMode::Initial
+        };
 
-                    if sync_state_failed {
-                        error!(
-                            "Failed to update sync state for '{}', but activities were synced",
-                            account_name
-                        );
-                    }
+        // Create import run
+        let import_run = match self
+            .sync_service
+            .create_import_run(&account_id, import_mode)
+            .await
+        {
+            Ok(run) => {
+                debug!(
+                    "Created import run {} for account '{}'",
+                    run.id, account_name
+                );
+                Some(run)
+            }
+            Err(e) => {
+                error!("Failed to create import run for '{}': {}", account_name, e);
+                None
+            }
+        };
+        let import_run_id = import_run.as_ref().map(|r| r.id.clone());
 
-                    // Always finalize import run (even if sync state update failed)
-                    if let Some(ref run_id) = import_run_id {
-                        let status = if needs_review > 0 {
-                            info!(
-                                "Import run {} has {} activities needing review",
-                                run_id, needs_review
-                            );
-                            ImportRunStatus::NeedsReview
-                        } else {
-                            ImportRunStatus::Applied
-                        };
-
-                        let _ = self
-                            .sync_service
-                            .finalize_import_run(run_id, summary, status, None)
-                            .await;
-                    }
+        let window_label = match (&start_date, &end_date_filter) {
+            (Some(s), Some(e)) => format!("{} -> {}", s, e),
+            _ => "ALL".to_string(),
+        };
+        info!(
+            "Syncing activities for account '{}' ({}): {}",
+            account_name, broker_account_id, window_label
+        );
 
-                    // Emit completion event
+        // Emit sync start event
+        self.progress_reporter.report_progress(
+            SyncProgressPayload::new(&account_id, &account_name, SyncStatus::Syncing)
+                .with_message(format!("Starting sync: {}", window_label)),
+        );
+
+        // Sync activities with pagination
+        match self
+            .sync_activity_windows(
+                api_client,
+                &account_id,
+                &account_name,
+                &broker_account_id,
+                &windows,
+                import_run_id.clone(),
+            )
+            .await
+        {
+            Ok(totals) => {
+                let ActivitySyncTotals {
+                    fetched,
+                    inserted,
+                    assets_created,
+                    needs_review,
+                    new_asset_ids,
+                    currencies,
+                } = totals;
+
+                // Build import run summary first (needed for both success and failure paths)
+                let summary = ImportRunSummary {
+                    fetched,
+                    inserted,
+                    updated: 0,
+                    skipped: 0,
+                    warnings: needs_review,
+                    errors: 0,
+                    removed: 0,
+                    assets_created,
+                };
+
+                // Finalize sync success (updates broker_sync_state table)
+                let last_synced_date = end_date.format("%Y-%m-%d").to_string();
+                let sync_state_failed = self
+                    .sync_service
+                    .finalize_activity_sync_success(
+                        account_id.clone(),
+                        last_synced_date,
+                        import_run_id.clone(),
+                    )
+                    .await
+                    .is_err();
+
+                if sync_state_failed {
+                    error!(
+                        "Failed to update sync state for '{}', but activities were synced",
+                        account_name
+                    );
+                }
+
+                // Always finalize import run (even if sync state update failed)
+                if let Some(ref run_id) = import_run_id {
                     let status = if needs_review > 0 {
-                        SyncStatus::NeedsReview
+                        info!(
+                            "Import run {} has {} activities needing review",
+                            run_id, needs_review
+                        );
+                        ImportRunStatus::NeedsReview
                     } else {
-                        SyncStatus::Complete
+                        ImportRunStatus::Applied
                     };
-                    self.progress_reporter.report_progress(
-                        SyncProgressPayload::new(&account_id, &account_name, status)
-                            .with_activities_fetched(fetched as usize)
-                            .with_message(format!(
-                                "Synced {} activities ({} need review)",
-                                inserted, needs_review
-                            )),
-                    );
-
-                    activities_summary.accounts_synced += 1;
-                    activities_summary.activities_upserted += inserted as usize;
-                    activities_summary.assets_inserted += assets_created as usize;
-                    activities_summary.new_asset_ids.extend(new_asset_ids);
-                }
-                Err(err) => {
-                    error!("Failed to sync activities for '{}': {}", account_name, err);
 
-                    // Finalize sync failure
                     let _ = self
                         .sync_service
-                        .finalize_activity_sync_failure(
-                            account_id.clone(),
-                            err.clone(),
-                            import_run_id.clone(),
-                        )
+                        .finalize_import_run(run_id, summary, status, None)
                         .await;
+                }
 
-                    // Finalize import run as failed
-                    if let Some(ref run_id) = import_run_id {
-                        let summary = ImportRunSummary::default();
-                        let _ = self
-                            .sync_service
-                            .finalize_import_run(
-                                run_id,
-                                summary,
-                                ImportRunStatus::Failed,
-                                Some(err.clone()),
-                            )
-                            .await;
-                    }
+                // Emit completion event
+                let status = if needs_review > 0 {
+                    SyncStatus::NeedsReview
+                } else {
+                    SyncStatus::Complete
+                };
+                self.progress_reporter.report_progress(
+                    SyncProgressPayload::new(&account_id, &account_name, status)
+                        .with_activities_fetched(fetched as usize)
+                        .with_message(format!(
+                            "Synced {} activities ({} need review)",
+                            inserted, needs_review
+                        )),
+                );
 
-                    // Emit failure event
-                    self.progress_reporter.report_progress(
-                        SyncProgressPayload::new(&account_id, &account_name, SyncStatus::Failed)
-                            .with_message(err.clone()),
+                if currencies
+                    .iter()
+                    .any(|c| !c.eq_ignore_ascii_case(&account.currency))
+                {
+                    warn!(
+                        "Account '{}' ({}) received activities in {:?}",
+                        account_name, account.currency, currencies
                     );
+                }
 
-                    activities_summary.accounts_failed += 1;
+                AccountSyncOutcome::Activities(SyncActivitiesResponse {
+                    accounts_synced: 1,
+                    activities_upserted: inserted as usize,
+                    assets_inserted: assets_created as usize,
+                    accounts_failed: 0,
+                    new_asset_ids,
+                    account_currencies: vec![AccountCurrencySummary {
+                        account_id,
+                        account_currency: account.currency.clone(),
+                        activity_currencies: currencies.into_iter().collect(),
+                    }],
+                })
+            }
+            Err(err) => {
+                error!("Failed to sync activities for '{}': {}", account_name, err);
+
+                // Finalize sync failure
+                let _ = self
+                    .sync_service
+                    .finalize_activity_sync_failure(
+                        account_id.clone(),
+                        err.clone(),
+                        import_run_id.clone(),
+                    )
+                    .await;
+
+                // Finalize import run as failed
+                if let Some(ref run_id) = import_run_id {
+                    let summary = ImportRunSummary::default();
+                    let _ = self
+                        .sync_service
+                        .finalize_import_run(
+                            run_id,
+                            summary,
+                            ImportRunStatus::Failed,
+                            Some(err.clone()),
+                        )
+                        .await;
                 }
+
+                // Emit failure event
+                self.progress_reporter.report_progress(
+                    SyncProgressPayload::new(&account_id, &account_name, SyncStatus::Failed)
+                        .with_message(err.clone()),
+                );
+
+                failed(TrackingMode::Transactions, err)
             }
         }
+    }
 
-        Ok((activities_summary, holdings_summary))
+    /// Compare positions computed from imported activities with the broker's
+    /// current holdings for an account.
+    ///
+    /// Differences larger than the `SyncConfig` reconciliation tolerances are
+    /// reported, not corrected. This is not part of [`Self::sync_all`]: the
+    /// computed positions come from the core holdings calculator, so callers
+    /// invoke it once activities have been imported and positions recalculated.
+    pub async fn reconcile_account_holdings(
+        &self,
+        api_client: &dyn BrokerApiClient,
+        account_id: &str,
+        broker_account_id: &str,
+        computed: &[ComputedPosition],
+    ) -> Result<ReconciliationReport, String> {
+        let holdings = api_client.get_account_holdings(broker_account_id).await;
+        self.metrics.record_request(&holdings);
+        let holdings = holdings.map_err(|e| e.to_string())?;
+
+        let report = reconcile_holdings(
+            account_id,
+            computed,
+            &holdings,
+            self.config.reconciliation_quantity_tolerance,
+            self.config.reconciliation_cost_tolerance,
+        );
+        if !report.is_reconciled() {
+            warn!(
+                "Account {} has {} position(s) that differ from broker holdings",
+                account_id,
+                report.discrepancies.len()
+            );
+        }
+        Ok(report)
     }
 
     /// Sync holdings for a single account (HOLDINGS tracking mode).
@@ -540,10 +1259,9 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
         );
 
         // Fetch holdings from broker API
-        let holdings = api_client
-            .get_account_holdings(broker_account_id)
-            .await
-            .map_err(|e| e.to_string())?;
+        let holdings = api_client.get_account_holdings(broker_account_id).await;
+        self.metrics.record_request(&holdings);
+        let holdings = holdings.map_err(|e| e.to_string())?;
 
         let positions_count = holdings.positions.as_ref().map(|p| p.len()).unwrap_or(0);
         let balances_count = holdings.balances.as_ref().map(|b| b.len()).unwrap_or(0);
@@ -554,15 +1272,23 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
         );
 
         // Save holdings as a snapshot
-        let (positions_saved, assets_created, new_asset_ids) = self
+        let saved = self
             .sync_service
             .save_broker_holdings(
                 account_id.to_string(),
-                holdings.balances.unwrap_or_default(),
-                holdings.positions.unwrap_or_default(),
+                holdings.balances.clone().unwrap_or_default(),
+                holdings.positions.clone().unwrap_or_default(),
             )
-            .await
-            .map_err(|e| format!("Failed to save broker holdings: {}", e))?;
+            .await;
+        self.metrics.record_persistence(&saved);
+        let (positions_saved, assets_created, new_asset_ids) =
+            saved.map_err(|e| format!("Failed to save broker holdings: {}", e))?;
+        self.record_audit(self.audit_entries(
+            AuditEntity::Holdings,
+            AuditAction::Upsert,
+            std::slice::from_ref(&holdings),
+            |_| account_id.to_string(),
+        ));
 
         // Emit completion event
         self.progress_reporter.report_progress(
@@ -577,34 +1303,161 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
         Ok((positions_saved, assets_created, new_asset_ids))
     }
 
+    /// Query windows (start, end) for one account's activity sync.
+    ///
+    /// A first sync with [`InitialSyncStrategy::RecentFirstChunked`] is split
+    /// into calendar months, most recent first; otherwise the whole window is
+    /// fetched at once.
+    fn activity_windows(
+        &self,
+        window: &ActivityQueryWindow,
+    ) -> Vec<(Option<String>, Option<String>)> {
+        let whole = vec![(window.start_date.clone(), window.end_date.clone())];
+        if window.incremental
+            || self.config.initial_sync_strategy != InitialSyncStrategy::RecentFirstChunked
+        {
+            return whole;
+        }
+
+        match (
+            parse_window_date(window.start_date.as_deref()),
+            parse_window_date(window.end_date.as_deref()),
+        ) {
+            (Some(start), Some(end)) => recent_first_monthly_windows(start, end)
+                .into_iter()
+                .map(|(s, e)| {
+                    (
+                        Some(s.format("%Y-%m-%d").to_string()),
+                        Some(e.format("%Y-%m-%d").to_string()),
+                    )
+                })
+                .collect(),
+            _ => whole,
+        }
+    }
+
+    /// Sync activities for each window in order, stopping at the first failure.
+    ///
+    /// Activities of completed windows stay imported if a later window fails.
+    /// With several windows, the checkpoint records the date range finished so
+    /// far after each window, and a later run skips windows inside that range.
+    async fn sync_activity_windows(
+        &self,
+        api_client: &dyn BrokerApiClient,
+        account_id: &str,
+        account_name: &str,
+        broker_account_id: &str,
+        windows: &[(Option<String>, Option<String>)],
+        import_run_id: Option<String>,
+    ) -> Result<ActivitySyncTotals, String> {
+        let stored = self.activity_sync_checkpoint(account_id);
+        let chunked = windows.len() > 1;
+        let mut completed = stored.as_ref().and_then(|c| {
+            Some((
+                parse_window_date(c.completed_start_date.as_deref())?,
+                parse_window_date(c.completed_end_date.as_deref())?,
+            ))
+        });
+
+        let mut totals = ActivitySyncTotals::default();
+        for (start_date, end_date) in windows {
+            let bounds = parse_window_date(start_date.as_deref())
+                .zip(parse_window_date(end_date.as_deref()));
+            if let (true, Some((start, end)), Some((from, to))) = (chunked, bounds, completed) {
+                if start >= from && end <= to {
+                    info!(
+                        "Skipping activities of '{}' from {} to {}: already imported",
+                        account_name, start, end
+                    );
+                    continue;
+                }
+            }
+
+            // Resume an interrupted sync of the same window, if any
+            let pending_offset =
+                resume_offset(stored.as_ref(), start_date.as_deref(), end_date.as_deref());
+            if pending_offset > 0 {
+                info!(
+                    "Resuming activity sync for {} from offset {}",
+                    account_id, pending_offset
+                );
+            }
+            let format = |date: chrono::NaiveDate| date.format("%Y-%m-%d").to_string();
+            let mut checkpoint = BrokerActivitiesCheckpoint {
+                start_date: start_date.clone(),
+                end_date: end_date.clone(),
+                pending_offset,
+                completed_start_date: completed.map(|(from, _)| format(from)),
+                completed_end_date: completed.map(|(_, to)| format(to)),
+            };
+
+            let window_totals = self
+                .sync_account_activities(
+                    api_client,
+                    account_id,
+                    account_name,
+                    broker_account_id,
+                    &checkpoint,
+                    import_run_id.clone(),
+                )
+                .await?;
+
+            if chunked {
+                if let Some(window) = bounds {
+                    completed = Some(extend_completed_range(completed, window));
+                }
+                checkpoint.pending_offset = 0;
+                checkpoint.completed_start_date = completed.map(|(from, _)| format(from));
+                checkpoint.completed_end_date = completed.map(|(_, to)| format(to));
+                self.save_activity_sync_checkpoint(account_id, account_name, checkpoint)
+                    .await;
+
+                self.progress_reporter.report_progress(
+                    SyncProgressPayload::new(account_id, account_name, SyncStatus::Syncing)
+                        .with_activities_fetched((totals.fetched + window_totals.fetched) as usize)
+                        .with_message(format!(
+                            "Synced {} activities from {} to {}",
+                            window_totals.inserted,
+                            start_date.as_deref().unwrap_or("start"),
+                            end_date.as_deref().unwrap_or("now")
+                        )),
+                );
+            }
+            totals.merge(window_totals);
+        }
+        Ok(totals)
+    }
+
     /// Sync activities for a single account with full pagination.
     ///
-    /// Returns (fetched, inserted, assets_created, needs_review, new_asset_ids).
-    #[allow(clippy::too_many_arguments)]
+    /// `window` holds the query window and the offset to start from; it is the
+    /// template for the checkpoint saved after each page.
     async fn sync_account_activities(
         &self,
         api_client: &dyn BrokerApiClient,
         account_id: &str,
         account_name: &str,
         broker_account_id: &str,
-        start_date: Option<&str>,
-        end_date: Option<&str>,
+        window: &BrokerActivitiesCheckpoint,
         import_run_id: Option<String>,
-    ) -> Result<(u32, u32, u32, u32, Vec<String>), String> {
-        let mut offset: i64 = 0;
+    ) -> Result<ActivitySyncTotals, String> {
+        let start_date = window.start_date.as_deref();
+        let end_date = window.end_date.as_deref();
+        let mut offset: i64 = window.pending_offset;
         let limit = self.config.page_limit;
         let mut pages_fetched: usize = 0;
         let mut last_page_first_id: Option<String> = None;
+        let mut last_reported_offset: Option<i64> = None;
 
-        let mut total_fetched: u32 = 0;
-        let mut total_inserted: u32 = 0;
-        let mut total_assets_created: u32 = 0;
-        let mut total_needs_review: u32 = 0;
-        let mut all_new_asset_ids: Vec<String> = Vec::new();
+        let mut totals = ActivitySyncTotals::default();
 
         loop {
             // Check max pages limit
             if pages_fetched >= self.config.max_pages {
+                warn!(
+                    "Stopping activity sync for '{}' after {} pages",
+                    account_name, pages_fetched
+                );
                 return Err(format!(
                     "Pagination exceeded max pages ({}). Aborting.",
                     self.config.max_pages
@@ -620,23 +1473,46 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
                     Some(offset),
                     Some(limit),
                 )
-                .await
-                .map_err(|e| e.to_string())?;
+                .await;
+            self.metrics.record_request(&page);
+            let page = page.map_err(|e| e.to_string())?;
 
             let data = page.data;
             pages_fetched += 1;
-            total_fetched += data.len() as u32;
-
-            let page_total = page.pagination.as_ref().and_then(|p| p.total);
+            self.metrics.add_activities_fetched(data.len());
+            totals.fetched += data.len() as u32;
+            totals.record_currencies(&data);
+
+            let pagination = page
+                .pagination
+                .as_ref()
+                .map(|p| p.normalized(offset, limit, data.len()));
+            let page_total = pagination.as_ref().and_then(|p| p.total);
+
+            // Check that the offset reported by the API advances between pages
+            let reported_offset = pagination.as_ref().and_then(|p| p.offset);
+            if let (Some(prev), Some(current)) = (last_reported_offset, reported_offset) {
+                if current <= prev {
+                    warn!(
+                        "Activity pagination for '{}' did not advance (offset {} after {})",
+                        account_name, current, prev
+                    );
+                    return Err(format!(
+                        "Pagination appears stuck (API reported offset {} after {}).",
+                        current, prev
+                    ));
+                }
+            }
+            last_reported_offset = reported_offset;
 
             // Emit progress event
             self.progress_reporter.report_progress(
                 SyncProgressPayload::new(account_id, account_name, SyncStatus::Syncing)
                     .with_page(pages_fetched)
-                    .with_activities_fetched(total_fetched as usize)
+                    .with_activities_fetched(totals.fetched as usize)
                     .with_message(format!(
                         "Fetched {} activities (total: {:?})",
-                        total_fetched, page_total
+                        totals.fetched, page_total
                     )),
             );
 
@@ -664,32 +1540,62 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
                     last_page_first_id = Some(first_id);
                 }
 
-                // Upsert activities
-                debug!(
-                    "Upserting {} activities for account '{}'...",
-                    data.len(),
-                    account_name
-                );
+                // Upsert activities in chunks; chunks already written stay
+                // committed if a later one fails.
+                let batch_size = self.config.persistence_batch_size.max(1);
+                let mut saved = 0;
+                for chunk in data.chunks(batch_size) {
+                    debug!(
+                        "Upserting {} activities for account '{}'...",
+                        chunk.len(),
+                        account_name
+                    );
 
-                let (upserted, assets, new_asset_ids, needs_review) = self
-                    .sync_service
-                    .upsert_account_activities(
-                        account_id.to_string(),
-                        import_run_id.clone(),
-                        data.clone(),
-                    )
-                    .await
-                    .map_err(|e| format!("Failed to upsert activities: {}", e))?;
+                    let upserted = self
+                        .sync_service
+                        .upsert_account_activities(
+                            account_id.to_string(),
+                            import_run_id.clone(),
+                            chunk.to_vec(),
+                        )
+                        .await;
+                    self.metrics.record_persistence(&upserted);
+                    let upserted =
+                        upserted.map_err(|e| format!("Failed to upsert activities: {}", e))?;
+                    self.metrics
+                        .add_activities_imported(upserted.activities_upserted);
+                    self.record_audit(
+                        self.activity_audit_entries(chunk, &upserted.written_activity_ids),
+                    );
 
-                info!(
-                    "Upserted {} activities, {} assets for '{}' ({} need review)",
-                    upserted, assets, account_name, needs_review
-                );
+                    info!(
+                        "Upserted {} activities, {} assets for '{}' ({} need review)",
+                        upserted.activities_upserted,
+                        upserted.assets_inserted,
+                        account_name,
+                        upserted.needs_review_count
+                    );
 
-                total_inserted += upserted as u32;
-                total_assets_created += assets as u32;
-                total_needs_review += needs_review as u32;
-                all_new_asset_ids.extend(new_asset_ids);
+                    totals.inserted += upserted.activities_upserted as u32;
+                    totals.assets_created += upserted.assets_inserted as u32;
+                    totals.needs_review += upserted.needs_review_count as u32;
+                    totals.new_asset_ids.extend(upserted.new_asset_ids);
+
+                    saved += chunk.len();
+                    if data.len() > batch_size {
+                        self.progress_reporter.report_progress(
+                            SyncProgressPayload::new(account_id, account_name, SyncStatus::Syncing)
+                                .with_page(pages_fetched)
+                                .with_activities_fetched(totals.fetched as usize)
+                                .with_message(format!(
+                                    "Saved {} of {} activities on page {}",
+                                    saved,
+                                    data.len(),
+                                    pages_fetched
+                                )),
+                        );
+                    }
+                }
             }
 
             let received = data.len() as i64;
@@ -701,21 +1607,9 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
             }
 
             // Check if there are more pages.
-            // Prefer explicit has_more when provided, then fall back to total/limit inference.
-            let has_more = match page.pagination.as_ref() {
-                Some(p) => match p.has_more {
-                    Some(true) => true,
-                    Some(false) => false,
-                    None => {
-                        if let Some(total) = p.total {
-                            next_offset < total
-                        } else if let Some(page_limit) = p.limit {
-                            received >= page_limit
-                        } else {
-                            received >= limit
-                        }
-                    }
-                },
+            // Normalized pagination always carries has_more (explicit or inferred).
+            let has_more = match pagination.as_ref() {
+                Some(p) => p.has_more == Some(true),
                 None => received >= limit,
             };
 
@@ -725,23 +1619,62 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
             if !has_more {
                 break;
             }
+
+            // Persist progress so an interrupted run can resume from here
+            let checkpoint = BrokerActivitiesCheckpoint {
+                pending_offset: offset,
+                ..window.clone()
+            };
+            self.save_activity_sync_checkpoint(account_id, account_name, checkpoint)
+                .await;
+        }
+
+        Ok(totals)
+    }
+
+    /// Store an activity sync checkpoint. Failures are logged, not returned:
+    /// a missing checkpoint only costs refetching on the next run.
+    async fn save_activity_sync_checkpoint(
+        &self,
+        account_id: &str,
+        account_name: &str,
+        checkpoint: BrokerActivitiesCheckpoint,
+    ) {
+        if let Err(e) = self
+            .sync_service
+            .save_activity_sync_checkpoint(account_id.to_string(), Some(checkpoint))
+            .await
+        {
+            warn!(
+                "Failed to save activity sync checkpoint for '{}': {}",
+                account_name, e
+            );
         }
+    }
 
-        Ok((
-            total_fetched,
-            total_inserted,
-            total_assets_created,
-            total_needs_review,
-            all_new_asset_ids,
-        ))
+    /// Checkpoint stored by an earlier, unfinished activity sync of an account.
+    fn activity_sync_checkpoint(&self, account_id: &str) -> Option<BrokerActivitiesCheckpoint> {
+        match self.sync_service.get_activity_sync_state(account_id) {
+            Ok(state) => state.and_then(|s| s.get_checkpoint::<BrokerActivitiesCheckpoint>()),
+            Err(e) => {
+                warn!(
+                    "Failed to read activity sync checkpoint for {}: {}",
+                    account_id, e
+                );
+                None
+            }
+        }
     }
 
-    /// Compute the activity query window for incremental sync.
+    /// Compute the activity query window for an account.
+    ///
+    /// Incremental syncs start the day before the last successful sync. A first
+    /// sync uses `default_lookback` when configured, otherwise the full history.
     fn compute_activity_query_window(
         &self,
         account_id: &str,
         end_date: chrono::NaiveDate,
-    ) -> Result<(Option<String>, Option<String>), String> {
+    ) -> Result<ActivityQueryWindow, String> {
         let sync_state = self
             .sync_service
             .get_activity_sync_state(account_id)
@@ -752,20 +1685,367 @@ impl<P: SyncProgressReporter> SyncOrchestrator<P> {
             .map(|dt| dt.date_naive())
             .map(|d| (d - chrono::Days::new(1)).min(end_date));
 
-        if let Some(d) = from_state {
-            return Ok((
-                Some(d.format("%Y-%m-%d").to_string()),
-                Some(end_date.format("%Y-%m-%d").to_string()),
-            ));
-        }
-
-        Ok((None, None))
+        let incremental = from_state.is_some();
+        let start = from_state.or_else(|| {
+            self.config
+                .default_lookback
+                .and_then(|lookback| end_date.checked_sub_signed(lookback))
+        });
+
+        Ok(match start {
+            Some(d) => ActivityQueryWindow {
+                start_date: Some(d.format("%Y-%m-%d").to_string()),
+                end_date: Some(end_date.format("%Y-%m-%d").to_string()),
+                incremental,
+            },
+            None => ActivityQueryWindow {
+                start_date: None,
+                end_date: None,
+                incremental,
+            },
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::broker::models::{
+        BrokerAccount, BrokerBrokerage, BrokerConnection, BrokerHoldingsResponse, HoldingsBalance,
+        HoldingsPosition, PaginatedUniversalActivity, PaginationDetails, SyncAccountsResponse,
+        SyncConnectionsResponse, UpsertActivitiesResult,
+    };
+    use crate::broker::progress::NoOpProgressReporter;
+    use crate::platform::Platform;
+    use crate::state::BrokerSyncState;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+    use wealthfolio_core::errors::Result as CoreResult;
+    use wealthfolio_core::sync::{ImportRun, ImportRunType, ReviewMode};
+
+    /// Broker API mock serving canned activity pages per broker account.
+    #[derive(Default)]
+    struct MockApiClient {
+        connections: Vec<BrokerConnection>,
+        accounts: Vec<BrokerAccount>,
+        /// Pages returned for each broker account, in request order.
+        activity_pages: HashMap<String, Vec<PaginatedUniversalActivity>>,
+        /// (broker_account_id, offset) of every activities request.
+        activity_calls: Mutex<Vec<(String, Option<i64>)>>,
+        /// Start date of every activities request.
+        activity_start_dates: Mutex<Vec<Option<String>>>,
+        /// Index of the activities request that fails, if any.
+        fail_activity_call: Option<usize>,
+        /// Holdings returned for every account.
+        holdings: BrokerHoldingsResponse,
+        holdings_calls: AtomicUsize,
+        in_flight: AtomicUsize,
+        max_in_flight: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl BrokerApiClient for MockApiClient {
+        async fn list_connections(&self) -> CoreResult<Vec<BrokerConnection>> {
+            Ok(self.connections.clone())
+        }
+
+        async fn list_accounts(
+            &self,
+            _authorization_ids: Option<Vec<String>>,
+        ) -> CoreResult<Vec<BrokerAccount>> {
+            Ok(self.accounts.clone())
+        }
+
+        async fn list_brokerages(&self) -> CoreResult<Vec<BrokerBrokerage>> {
+            Ok(Vec::new())
+        }
+
+        async fn get_account_activities(
+            &self,
+            account_id: &str,
+            start_date: Option<&str>,
+            _end_date: Option<&str>,
+            offset: Option<i64>,
+            _limit: Option<i64>,
+        ) -> CoreResult<PaginatedUniversalActivity> {
+            let (page_index, call_index) = {
+                let mut calls = self.activity_calls.lock().unwrap();
+                let index = calls.iter().filter(|(id, _)| id == account_id).count();
+                calls.push((account_id.to_string(), offset));
+                (index, calls.len() - 1)
+            };
+            self.activity_start_dates
+                .lock()
+                .unwrap()
+                .push(start_date.map(str::to_string));
+            if self.fail_activity_call == Some(call_index) {
+                return Err(wealthfolio_core::errors::Error::Unexpected(
+                    "gateway timeout".to_string(),
+                ));
+            }
+
+            let current = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(current, Ordering::SeqCst);
+            for _ in 0..3 {
+                tokio::task::yield_now().await;
+            }
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            Ok(self
+                .activity_pages
+                .get(account_id)
+                .and_then(|pages| pages.get(page_index))
+                .cloned()
+                .unwrap_or_default())
+        }
+
+        async fn get_account_holdings(
+            &self,
+            _account_id: &str,
+        ) -> CoreResult<BrokerHoldingsResponse> {
+            self.holdings_calls.fetch_add(1, Ordering::SeqCst);
+            Ok(self.holdings.clone())
+        }
+    }
+
+    /// Sync service mock that keeps everything in memory.
+    #[derive(Default)]
+    struct MockSyncService {
+        accounts: Vec<Account>,
+        /// (local_account_id, activity count) of every upsert call.
+        upserts: Mutex<Vec<(String, usize)>>,
+        /// Sync state returned for every account.
+        sync_state: Option<BrokerSyncState>,
+        /// Every checkpoint saved, in call order.
+        checkpoints: Mutex<Vec<(String, Option<BrokerActivitiesCheckpoint>)>>,
+        /// Broker account IDs reported as created by `sync_accounts`.
+        created_broker_ids: Vec<String>,
+        /// Platform IDs reported as removed by `sync_connections`.
+        removed_platform_ids: Vec<String>,
+        /// Stored ID of a broker activity, or `None` for a user-modified one
+        /// the upsert skips. Other activities are stored under their own ID.
+        stored_activity_ids: HashMap<String, Option<String>>,
+        /// When the last holdings snapshot was saved.
+        holdings_saved_at: Mutex<Option<chrono::DateTime<chrono::Utc>>>,
+    }
+
+    #[async_trait]
+    impl BrokerSyncServiceTrait for MockSyncService {
+        async fn sync_connections(
+            &self,
+            connections: Vec<BrokerConnection>,
+        ) -> CoreResult<SyncConnectionsResponse> {
+            Ok(SyncConnectionsResponse {
+                synced: connections.len(),
+                platforms_created: 0,
+                platforms_updated: 0,
+                platforms_removed: self.removed_platform_ids.len(),
+                // Connections without a brokerage are skipped
+                synced_connection_ids: connections
+                    .iter()
+                    .filter(|c| c.brokerage.is_some())
+                    .map(|c| c.id.clone())
+                    .collect(),
+                removed_platform_ids: self.removed_platform_ids.clone(),
+            })
+        }
+
+        async fn sync_accounts(
+            &self,
+            broker_accounts: Vec<BrokerAccount>,
+        ) -> CoreResult<SyncAccountsResponse> {
+            let new_accounts_info: Vec<NewAccountInfo> = self
+                .created_broker_ids
+                .iter()
+                .map(|id| NewAccountInfo {
+                    local_account_id: format!("local-{}", id),
+                    provider_account_id: id.clone(),
+                    default_name: id.clone(),
+                    currency: "USD".to_string(),
+                    institution_name: None,
+                })
+                .collect();
+            Ok(SyncAccountsResponse {
+                synced: broker_accounts.len(),
+                created: new_accounts_info.len(),
+                updated: 0,
+                skipped: broker_accounts.len() - new_accounts_info.len(),
+                created_accounts: Vec::new(),
+                new_accounts_info,
+            })
+        }
+
+        fn get_synced_accounts(&self) -> CoreResult<Vec<Account>> {
+            Ok(self.accounts.clone())
+        }
+
+        fn get_platforms(&self) -> CoreResult<Vec<Platform>> {
+            Ok(Vec::new())
+        }
+
+        fn get_activity_sync_state(
+            &self,
+            _account_id: &str,
+        ) -> CoreResult<Option<BrokerSyncState>> {
+            Ok(self.sync_state.clone())
+        }
+
+        async fn mark_activity_sync_attempt(&self, _account_id: String) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn save_activity_sync_checkpoint(
+            &self,
+            account_id: String,
+            checkpoint: Option<BrokerActivitiesCheckpoint>,
+        ) -> CoreResult<()> {
+            self.checkpoints
+                .lock()
+                .unwrap()
+                .push((account_id, checkpoint));
+            Ok(())
+        }
+
+        async fn upsert_account_activities(
+            &self,
+            account_id: String,
+            _import_run_id: Option<String>,
+            activities: Vec<AccountUniversalActivity>,
+        ) -> CoreResult<UpsertActivitiesResult> {
+            let count = activities.len();
+            self.upserts.lock().unwrap().push((account_id, count));
+
+            // Like the real service: activities without an ID are dropped and
+            // a repeated ID is written once
+            let mut seen = HashSet::new();
+            let written_activity_ids = activities
+                .into_iter()
+                .filter_map(|a| a.id)
+                .filter(|id| !id.is_empty() && seen.insert(id.clone()))
+                .filter_map(|id| {
+                    let stored_id = match self.stored_activity_ids.get(&id) {
+                        Some(stored_id) => stored_id.clone()?,
+                        None => id.clone(),
+                    };
+                    Some((id, stored_id))
+                })
+                .collect();
+            Ok(UpsertActivitiesResult {
+                activities_upserted: count,
+                written_activity_ids,
+                ..Default::default()
+            })
+        }
+
+        async fn finalize_activity_sync_success(
+            &self,
+            _account_id: String,
+            _last_synced_date: String,
+            _import_run_id: Option<String>,
+        ) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn finalize_activity_sync_failure(
+            &self,
+            _account_id: String,
+            _error: String,
+            _import_run_id: Option<String>,
+        ) -> CoreResult<()> {
+            Ok(())
+        }
+
+        fn get_all_sync_states(&self) -> CoreResult<Vec<BrokerSyncState>> {
+            Ok(Vec::new())
+        }
+
+        fn get_import_runs(
+            &self,
+            _run_type: Option<&str>,
+            _limit: i64,
+            _offset: i64,
+        ) -> CoreResult<Vec<ImportRun>> {
+            Ok(Vec::new())
+        }
+
+        async fn create_import_run(
+            &self,
+            account_id: &str,
+            mode: ImportRunMode,
+        ) -> CoreResult<ImportRun> {
+            Ok(ImportRun::new(
+                account_id.to_string(),
+                "test".to_string(),
+                ImportRunType::Sync,
+                mode,
+                ReviewMode::Never,
+            ))
+        }
+
+        async fn finalize_import_run(
+            &self,
+            _run_id: &str,
+            _summary: ImportRunSummary,
+            _status: ImportRunStatus,
+            _error: Option<String>,
+        ) -> CoreResult<()> {
+            Ok(())
+        }
+
+        async fn save_broker_holdings(
+            &self,
+            _account_id: String,
+            _balances: Vec<HoldingsBalance>,
+            positions: Vec<HoldingsPosition>,
+        ) -> CoreResult<(usize, usize, Vec<String>)> {
+            *self.holdings_saved_at.lock().unwrap() = Some(chrono::Utc::now());
+            Ok((positions.len(), 0, Vec::new()))
+        }
+    }
+
+    fn local_account(id: &str, tracking_mode: TrackingMode) -> Account {
+        Account {
+            id: id.to_string(),
+            name: format!("Account {}", id),
+            currency: "USD".to_string(),
+            provider_account_id: Some(format!("broker-{}", id)),
+            tracking_mode,
+            ..Default::default()
+        }
+    }
+
+    fn broker_account(id: &str) -> BrokerAccount {
+        BrokerAccount {
+            id: Some(format!("broker-{}", id)),
+            sync_enabled: true,
+            ..Default::default()
+        }
+    }
+
+    fn activity_page(ids: &[&str], has_more: bool) -> PaginatedUniversalActivity {
+        PaginatedUniversalActivity {
+            data: ids
+                .iter()
+                .map(|id| AccountUniversalActivity {
+                    id: Some(id.to_string()),
+                    ..Default::default()
+                })
+                .collect(),
+            pagination: Some(PaginationDetails {
+                has_more: Some(has_more),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn orchestrator(
+        service: Arc<MockSyncService>,
+        config: SyncConfig,
+    ) -> SyncOrchestrator<NoOpProgressReporter> {
+        SyncOrchestrator::new(service, Arc::new(NoOpProgressReporter), config)
+    }
 
     #[test]
     fn test_sync_config_default() {
@@ -773,4 +2053,885 @@ mod tests {
         assert_eq!(config.page_limit, 1000);
         assert_eq!(config.max_pages, 10_000);
     }
+
+    #[test]
+    fn test_sync_config_page_limit_is_clamped() {
+        let mut config = SyncConfig {
+            page_limit: 0,
+            ..SyncConfig::default()
+        };
+        assert_eq!(config.clamped_page_limit(), MIN_PAGE_LIMIT);
+
+        config.page_limit = 5_000;
+        assert_eq!(config.clamped_page_limit(), MAX_PAGE_LIMIT);
+
+        config.page_limit = 250;
+        assert_eq!(config.clamped_page_limit(), 250);
+    }
+
+    #[test]
+    fn test_sync_config_builder_accepts_valid_settings() {
+        let config = SyncConfig::builder()
+            .page_limit(250)
+            .max_pages(50)
+            .max_account_concurrency(4)
+            .build()
+            .unwrap();
+        assert_eq!(config.page_limit, 250);
+        assert_eq!(config.max_pages, 50);
+        assert_eq!(config.max_account_concurrency, 4);
+    }
+
+    #[test]
+    fn test_sync_config_builder_rejects_page_limit_out_of_range() {
+        assert_eq!(
+            SyncConfig::builder().page_limit(0).build().unwrap_err(),
+            ConfigError::PageLimitOutOfRange(0)
+        );
+        assert_eq!(
+            SyncConfig::builder().page_limit(1001).build().unwrap_err(),
+            ConfigError::PageLimitOutOfRange(1001)
+        );
+    }
+
+    #[test]
+    fn test_sync_config_builder_rejects_zero_max_pages() {
+        assert_eq!(
+            SyncConfig::builder().max_pages(0).build().unwrap_err(),
+            ConfigError::ZeroMaxPages
+        );
+    }
+
+    #[test]
+    fn test_sync_config_builder_rejects_zero_concurrency() {
+        assert_eq!(
+            SyncConfig::builder()
+                .max_account_concurrency(0)
+                .build()
+                .unwrap_err(),
+            ConfigError::ZeroAccountConcurrency
+        );
+    }
+
+    #[test]
+    fn test_sync_config_builder_rejects_negative_lookback() {
+        assert_eq!(
+            SyncConfig::builder()
+                .default_lookback(chrono::Duration::days(-1))
+                .build()
+                .unwrap_err(),
+            ConfigError::NegativeLookback
+        );
+    }
+
+    #[test]
+    fn test_sync_config_builder_rejects_zero_persistence_batch_size() {
+        assert_eq!(
+            SyncConfig::builder()
+                .persistence_batch_size(0)
+                .build()
+                .unwrap_err(),
+            ConfigError::ZeroPersistenceBatchSize
+        );
+    }
+
+    #[test]
+    fn test_sync_config_builder_rejects_chunked_sync_without_lookback() {
+        assert_eq!(
+            SyncConfig::builder()
+                .initial_sync_strategy(InitialSyncStrategy::RecentFirstChunked)
+                .build()
+                .unwrap_err(),
+            ConfigError::ChunkedSyncWithoutLookback
+        );
+    }
+
+    #[test]
+    fn test_sync_config_builder_rejects_negative_reconciliation_tolerance() {
+        assert_eq!(
+            SyncConfig::builder()
+                .reconciliation_quantity_tolerance(rust_decimal::Decimal::new(-1, 2))
+                .build()
+                .unwrap_err(),
+            ConfigError::NegativeReconciliationTolerance
+        );
+        assert_eq!(
+            SyncConfig::builder()
+                .reconciliation_cost_tolerance(rust_decimal::Decimal::new(-1, 2))
+                .build()
+                .unwrap_err(),
+            ConfigError::NegativeReconciliationTolerance
+        );
+    }
+
+    #[test]
+    fn test_first_sync_window_uses_default_lookback() {
+        let config = SyncConfig::builder()
+            .default_lookback(chrono::Duration::days(730))
+            .build()
+            .unwrap();
+        let orchestrator = orchestrator(Arc::new(MockSyncService::default()), config);
+        let end_date = chrono::NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+
+        let window = orchestrator
+            .compute_activity_query_window("acc", end_date)
+            .unwrap();
+
+        assert_eq!(
+            window,
+            ActivityQueryWindow {
+                start_date: Some("2022-07-01".to_string()),
+                end_date: Some("2024-06-30".to_string()),
+                incremental: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_first_sync_window_without_lookback_fetches_full_history() {
+        let orchestrator =
+            orchestrator(Arc::new(MockSyncService::default()), SyncConfig::default());
+        let end_date = chrono::NaiveDate::from_ymd_opt(2024, 6, 30).unwrap();
+
+        let window = orchestrator
+            .compute_activity_query_window("acc", end_date)
+            .unwrap();
+
+        assert_eq!(window.start_date, None);
+        assert!(!window.incremental);
+    }
+
+    #[test]
+    fn test_chunked_initial_sync_is_most_recent_first() {
+        let config = SyncConfig::builder()
+            .default_lookback(chrono::Duration::days(90))
+            .initial_sync_strategy(InitialSyncStrategy::RecentFirstChunked)
+            .build()
+            .unwrap();
+        let orchestrator = orchestrator(Arc::new(MockSyncService::default()), config);
+        let window = ActivityQueryWindow {
+            start_date: Some("2024-04-15".to_string()),
+            end_date: Some("2024-06-20".to_string()),
+            incremental: false,
+        };
+
+        let windows = orchestrator.activity_windows(&window);
+
+        let expected = [
+            ("2024-06-01", "2024-06-20"),
+            ("2024-05-01", "2024-05-31"),
+            ("2024-04-15", "2024-04-30"),
+        ];
+        assert_eq!(
+            windows,
+            expected
+                .iter()
+                .map(|(s, e)| (Some(s.to_string()), Some(e.to_string())))
+                .collect::<Vec<_>>()
+        );
+
+        let incremental = ActivityQueryWindow {
+            incremental: true,
+            ..window
+        };
+        assert_eq!(orchestrator.activity_windows(&incremental).len(), 1);
+    }
+
+    #[test]
+    fn test_activity_totals_collect_distinct_currencies() {
+        use crate::broker::models::{
+            AccountUniversalActivityCurrency, AccountUniversalActivitySymbol,
+        };
+
+        let with_currency = |code: &str| AccountUniversalActivity {
+            currency: Some(AccountUniversalActivityCurrency {
+                code: Some(code.to_string()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let batch = vec![
+            with_currency("TZS"),
+            with_currency("usd"),
+            with_currency("TZS"),
+            // Falls back to the symbol currency when the activity has none
+            AccountUniversalActivity {
+                symbol: Some(AccountUniversalActivitySymbol {
+                    currency: Some(AccountUniversalActivityCurrency {
+                        code: Some("KES".to_string()),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            AccountUniversalActivity::default(),
+        ];
+
+        let mut totals = ActivitySyncTotals::default();
+        totals.record_currencies(&batch);
+
+        let currencies: Vec<_> = totals.currencies.into_iter().collect();
+        assert_eq!(currencies, vec!["KES", "TZS", "USD"]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_runs_accounts_concurrently() {
+        let ids = ["a", "b", "c", "d", "e"];
+        let service = Arc::new(MockSyncService {
+            accounts: ids
+                .iter()
+                .map(|id| local_account(id, TrackingMode::Transactions))
+                .collect(),
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: ids.iter().map(|id| broker_account(id)).collect(),
+            activity_pages: ids
+                .iter()
+                .map(|id| {
+                    let first = format!("{}-1", id);
+                    let second = format!("{}-2", id);
+                    (
+                        format!("broker-{}", id),
+                        vec![activity_page(&[&first, &second], false)],
+                    )
+                })
+                .collect(),
+            ..Default::default()
+        };
+
+        let config = SyncConfig {
+            max_account_concurrency: 3,
+            ..SyncConfig::default()
+        };
+        let result = orchestrator(service.clone(), config)
+            .sync_all(&api)
+            .await
+            .unwrap();
+
+        let activities = result.activities_synced.unwrap();
+        assert!(result.success);
+        assert_eq!(activities.accounts_synced, 5);
+        assert_eq!(activities.activities_upserted, 10);
+        assert_eq!(service.upserts.lock().unwrap().len(), 5);
+        assert_eq!(api.max_in_flight.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_collects_account_errors_without_aborting() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![
+                local_account("ok", TrackingMode::Transactions),
+                local_account("bad", TrackingMode::Transactions),
+            ],
+            ..Default::default()
+        });
+        let stuck_page = activity_page(&["same"], true);
+        let api = MockApiClient {
+            accounts: vec![broker_account("ok"), broker_account("bad")],
+            activity_pages: HashMap::from([
+                (
+                    "broker-ok".to_string(),
+                    vec![activity_page(&["ok-1"], false)],
+                ),
+                (
+                    "broker-bad".to_string(),
+                    vec![stuck_page.clone(), stuck_page],
+                ),
+            ]),
+            ..Default::default()
+        };
+
+        let config = SyncConfig {
+            max_account_concurrency: 2,
+            ..SyncConfig::default()
+        };
+        let result = orchestrator(service, config).sync_all(&api).await.unwrap();
+
+        let activities = result.activities_synced.unwrap();
+        assert!(!result.success);
+        assert_eq!(activities.accounts_synced, 1);
+        assert_eq!(activities.accounts_failed, 1);
+        assert_eq!(result.account_errors.len(), 1);
+        assert_eq!(result.account_errors[0].account_id, "bad");
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_resumes_from_stored_offset() {
+        // A previous run stopped after persisting the first two activities.
+        let mut state = BrokerSyncState::new("acc".to_string(), "snaptrade".to_string());
+        state
+            .set_checkpoint(&BrokerActivitiesCheckpoint {
+                start_date: None,
+                end_date: None,
+                pending_offset: 2,
+                completed_start_date: None,
+                completed_end_date: None,
+            })
+            .unwrap();
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            sync_state: Some(state),
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            activity_pages: HashMap::from([(
+                "broker-acc".to_string(),
+                vec![
+                    activity_page(&["c", "d"], true),
+                    activity_page(&["e"], false),
+                ],
+            )]),
+            ..Default::default()
+        };
+
+        let result = orchestrator(service.clone(), SyncConfig::default())
+            .sync_all(&api)
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        let offsets: Vec<Option<i64>> = api
+            .activity_calls
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, offset)| *offset)
+            .collect();
+        assert_eq!(offsets, vec![Some(2), Some(4)]);
+        assert_eq!(
+            *service.checkpoints.lock().unwrap(),
+            vec![(
+                "acc".to_string(),
+                Some(BrokerActivitiesCheckpoint {
+                    start_date: None,
+                    end_date: None,
+                    pending_offset: 4,
+                    completed_start_date: None,
+                    completed_end_date: None,
+                })
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_chunked_sync_skips_windows_completed_by_a_failed_run() {
+        let config = || {
+            SyncConfig::builder()
+                .default_lookback(chrono::Duration::days(40))
+                .initial_sync_strategy(InitialSyncStrategy::RecentFirstChunked)
+                .build()
+                .unwrap()
+        };
+
+        // First run: the most recent window is imported, the next one fails.
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            activity_pages: HashMap::from([(
+                "broker-acc".to_string(),
+                vec![activity_page(&["a"], false)],
+            )]),
+            fail_activity_call: Some(1),
+            ..Default::default()
+        };
+        let result = orchestrator(service.clone(), config())
+            .sync_all(&api)
+            .await
+            .unwrap();
+        assert!(!result.success);
+        assert_eq!(service.upserts.lock().unwrap().len(), 1);
+
+        let first_run = api.activity_start_dates.lock().unwrap().clone();
+        assert_eq!(first_run.len(), 2);
+        let (_, checkpoint) = service.checkpoints.lock().unwrap().last().cloned().unwrap();
+        let checkpoint = checkpoint.unwrap();
+        assert_eq!(checkpoint.pending_offset, 0);
+        assert_eq!(checkpoint.completed_start_date, first_run[0]);
+
+        // Second run resumes from the stored checkpoint.
+        let mut state = BrokerSyncState::new("acc".to_string(), "snaptrade".to_string());
+        state.set_checkpoint(&checkpoint).unwrap();
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            sync_state: Some(state),
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            ..Default::default()
+        };
+        let result = orchestrator(service, config())
+            .sync_all(&api)
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let second_run = api.activity_start_dates.lock().unwrap().clone();
+        assert!(!second_run.contains(&first_run[0]));
+        assert_eq!(second_run.first(), first_run.get(1));
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_ignores_checkpoint_of_a_different_window() {
+        // Same start date, but the window ended on another day, so the stored
+        // offset points into a different result set.
+        let end_date = chrono::Utc::now().date_naive();
+        let start_date = end_date - chrono::Duration::days(30);
+        let mut state = BrokerSyncState::new("acc".to_string(), "snaptrade".to_string());
+        state
+            .set_checkpoint(&BrokerActivitiesCheckpoint {
+                start_date: Some(start_date.format("%Y-%m-%d").to_string()),
+                end_date: Some("2000-01-01".to_string()),
+                pending_offset: 2,
+                completed_start_date: None,
+                completed_end_date: None,
+            })
+            .unwrap();
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            sync_state: Some(state),
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            activity_pages: HashMap::from([(
+                "broker-acc".to_string(),
+                vec![activity_page(&["a"], false)],
+            )]),
+            ..Default::default()
+        };
+
+        let config = SyncConfig::builder()
+            .default_lookback(chrono::Duration::days(30))
+            .build()
+            .unwrap();
+        let result = orchestrator(service, config).sync_all(&api).await.unwrap();
+
+        assert!(result.success);
+        assert_eq!(
+            *api.activity_calls.lock().unwrap(),
+            vec![("broker-acc".to_string(), Some(0))]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_all_records_metrics() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            activity_pages: HashMap::from([(
+                "broker-acc".to_string(),
+                vec![
+                    activity_page(&["a", "b"], true),
+                    activity_page(&["c"], false),
+                ],
+            )]),
+            ..Default::default()
+        };
+
+        let metrics = Arc::new(SyncMetrics::new());
+        orchestrator(service, SyncConfig::default())
+            .with_metrics(metrics.clone())
+            .sync_all(&api)
+            .await
+            .unwrap();
+
+        // connections + accounts + two activity pages
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.requests, 4);
+        assert_eq!(snapshot.activities_fetched, 3);
+        assert_eq!(snapshot.activities_imported, 3);
+        assert_eq!(snapshot.api_errors, 0);
+        assert_eq!(snapshot.account_failures, 0);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_sync_sums_reported_totals() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![
+                local_account("a", TrackingMode::Transactions),
+                local_account("b", TrackingMode::Transactions),
+                local_account("holdings", TrackingMode::Holdings),
+                local_account("disabled", TrackingMode::Transactions),
+            ],
+            ..Default::default()
+        });
+        let with_total = |total: i64| PaginatedUniversalActivity {
+            data: vec![AccountUniversalActivity::default()],
+            pagination: Some(PaginationDetails {
+                total: Some(total),
+                ..Default::default()
+            }),
+        };
+        let api = MockApiClient {
+            accounts: vec![
+                broker_account("a"),
+                broker_account("b"),
+                broker_account("holdings"),
+                BrokerAccount {
+                    sync_enabled: false,
+                    ..broker_account("disabled")
+                },
+            ],
+            activity_pages: HashMap::from([
+                ("broker-a".to_string(), vec![with_total(120)]),
+                ("broker-b".to_string(), vec![with_total(30)]),
+            ]),
+            ..Default::default()
+        };
+
+        let estimate = orchestrator(service.clone(), SyncConfig::default())
+            .estimate_sync(&api)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            estimate,
+            SyncEstimate {
+                accounts: 2,
+                estimated_activities: 150,
+                accounts_without_total: 0,
+            }
+        );
+        assert!(service.upserts.lock().unwrap().is_empty());
+        assert_eq!(api.holdings_calls.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_sync_single_account_only_touches_that_account() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![
+                local_account("a", TrackingMode::Transactions),
+                local_account("b", TrackingMode::Transactions),
+            ],
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("a"), broker_account("b")],
+            activity_pages: HashMap::from([
+                ("broker-a".to_string(), vec![activity_page(&["a-1"], false)]),
+                (
+                    "broker-b".to_string(),
+                    vec![activity_page(&["b-1", "b-2"], false)],
+                ),
+            ]),
+            ..Default::default()
+        };
+        let orchestrator = orchestrator(service.clone(), SyncConfig::default());
+
+        let result = orchestrator.sync_single_account(&api, "b").await.unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.activities_synced.unwrap().activities_upserted, 2);
+        assert!(api
+            .activity_calls
+            .lock()
+            .unwrap()
+            .iter()
+            .all(|(id, _)| id == "broker-b"));
+        assert_eq!(*service.upserts.lock().unwrap(), vec![("b".to_string(), 2)]);
+
+        assert!(orchestrator
+            .sync_single_account(&api, "missing")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_activities_are_persisted_in_batches() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            ..Default::default()
+        });
+        let ids: Vec<String> = (0..1200).map(|i| format!("act-{}", i)).collect();
+        let ids: Vec<&str> = ids.iter().map(String::as_str).collect();
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            activity_pages: HashMap::from([(
+                "broker-acc".to_string(),
+                vec![activity_page(&ids, false)],
+            )]),
+            ..Default::default()
+        };
+
+        let config = SyncConfig::builder()
+            .persistence_batch_size(500)
+            .build()
+            .unwrap();
+        let result = orchestrator(service.clone(), config)
+            .sync_all(&api)
+            .await
+            .unwrap();
+
+        assert_eq!(result.activities_synced.unwrap().activities_upserted, 1200);
+        let batch_sizes: Vec<usize> = service
+            .upserts
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(_, count)| *count)
+            .collect();
+        assert_eq!(batch_sizes, vec![500, 500, 200]);
+    }
+
+    #[tokio::test]
+    async fn test_pagination_stops_when_reported_offset_does_not_advance() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            ..Default::default()
+        });
+        // Distinct activities on every page, but the API keeps reporting offset 0.
+        let frozen_page = |id: &str| PaginatedUniversalActivity {
+            pagination: Some(PaginationDetails {
+                offset: Some(0),
+                has_more: Some(true),
+                ..Default::default()
+            }),
+            ..activity_page(&[id], true)
+        };
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            activity_pages: HashMap::from([(
+                "broker-acc".to_string(),
+                (0..50)
+                    .map(|i| frozen_page(&format!("act-{}", i)))
+                    .collect(),
+            )]),
+            ..Default::default()
+        };
+
+        let result = orchestrator(service, SyncConfig::default())
+            .sync_all(&api)
+            .await
+            .unwrap();
+
+        assert!(!result.success);
+        assert!(result.account_errors[0].error.contains("stuck"));
+        assert_eq!(api.activity_calls.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_pagination_stops_at_max_pages() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![local_account("acc", TrackingMode::Transactions)],
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("acc")],
+            activity_pages: HashMap::from([(
+                "broker-acc".to_string(),
+                (0..50)
+                    .map(|i| activity_page(&[&format!("act-{}", i)], true))
+                    .collect(),
+            )]),
+            ..Default::default()
+        };
+
+        let config = SyncConfig::builder().max_pages(3).build().unwrap();
+        let result = orchestrator(service, config).sync_all(&api).await.unwrap();
+
+        assert!(!result.success);
+        assert!(result.account_errors[0].error.contains("max pages"));
+        assert_eq!(api.activity_calls.lock().unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_holdings_only_mode_skips_activity_requests() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![
+                local_account("txn", TrackingMode::Transactions),
+                local_account("hold", TrackingMode::Holdings),
+            ],
+            ..Default::default()
+        });
+        let api = MockApiClient {
+            accounts: vec![broker_account("txn"), broker_account("hold")],
+            activity_pages: HashMap::from([(
+                "broker-txn".to_string(),
+                vec![activity_page(&["a"], false)],
+            )]),
+            ..Default::default()
+        };
+
+        let config = SyncConfig::builder()
+            .sync_mode(SyncMode::HoldingsOnly)
+            .build()
+            .unwrap();
+        let result = orchestrator(service.clone(), config)
+            .sync_all(&api)
+            .await
+            .unwrap();
+
+        assert!(result.success);
+        assert_eq!(result.sync_mode, SyncMode::HoldingsOnly);
+        assert_eq!(result.holdings_synced.unwrap().accounts_synced, 1);
+        assert!(api.activity_calls.lock().unwrap().is_empty());
+        assert_eq!(api.holdings_calls.load(Ordering::SeqCst), 1);
+        assert!(service.upserts.lock().unwrap().is_empty());
+    }
+
+    #[derive(Default)]
+    struct RecordingAuditLogger {
+        entries: Mutex<Vec<AuditEntry>>,
+    }
+
+    impl AuditLogger for RecordingAuditLogger {
+        fn record(&self, entry: AuditEntry) {
+            self.entries.lock().unwrap().push(entry);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_audit_logger_records_successful_writes() {
+        let service = Arc::new(MockSyncService {
+            accounts: vec![
+                local_account("txn", TrackingMode::Transactions),
+                local_account("hold", TrackingMode::Holdings),
+            ],
+            created_broker_ids: vec!["broker-new".to_string()],
+            removed_platform_ids: vec!["WEALTHSIMPLE".to_string()],
+            stored_activity_ids: HashMap::from([
+                // Matched an existing activity by idempotency key
+                ("a2".to_string(), Some("existing-a2".to_string())),
+                // User-modified, skipped by the upsert
+                ("a3".to_string(), None),
+            ]),
+            ..Default::default()
+        });
+        let connection = |id: &str, brokerage: Option<serde_json::Value>| -> BrokerConnection {
+            serde_json::from_value(serde_json::json!({ "id": id, "brokerage": brokerage })).unwrap()
+        };
+        let api = MockApiClient {
+            connections: vec![
+                connection("c1", Some(serde_json::json!({ "slug": "QUESTRADE" }))),
+                connection("c2", None),
+            ],
+            accounts: vec![
+                broker_account("txn"),
+                broker_account("hold"),
+                broker_account("new"),
+            ],
+            activity_pages: HashMap::from([(
+                "broker-txn".to_string(),
+                vec![activity_page(&["a1", "a2", "", "a1", "a3"], false)],
+            )]),
+            ..Default::default()
+        };
+        let audit = Arc::new(RecordingAuditLogger::default());
+
+        let result = orchestrator(service.clone(), SyncConfig::default())
+            .with_audit_logger(audit.clone())
+            .sync_all(&api)
+            .await
+            .unwrap();
+        assert!(result.success);
+
+        let mut entries: Vec<(AuditEntity, String)> = audit
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|e| (e.entity, e.entity_id.clone()))
+            .collect();
+        entries.sort_by(|a, b| a.1.cmp(&b.1));
+        assert_eq!(
+            entries,
+            vec![
+                (AuditEntity::Platform, "WEALTHSIMPLE".to_string()),
+                (AuditEntity::Activity, "a1".to_string()),
+                (AuditEntity::Account, "broker-new".to_string()),
+                (AuditEntity::Connection, "c1".to_string()),
+                (AuditEntity::Activity, "existing-a2".to_string()),
+                (AuditEntity::Holdings, "hold".to_string()),
+            ]
+        );
+        assert!(audit.entries.lock().unwrap().iter().all(|e| {
+            let expected = match e.entity {
+                AuditEntity::Platform => AuditAction::Delete,
+                _ => AuditAction::Upsert,
+            };
+            e.action == expected && e.payload_hash.len() == 64
+        }));
+
+        // Entries are stamped once the write has succeeded
+        let saved_at = service.holdings_saved_at.lock().unwrap().unwrap();
+        let holdings_entry = audit
+            .entries
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|e| e.entity == AuditEntity::Holdings)
+            .cloned()
+            .unwrap();
+        assert!(holdings_entry.timestamp >= saved_at);
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_account_holdings_uses_configured_tolerances() {
+        use crate::broker::models::{HoldingsInnerSymbol, HoldingsSymbol};
+        use rust_decimal::Decimal;
+
+        let api = MockApiClient {
+            holdings: BrokerHoldingsResponse {
+                positions: Some(vec![HoldingsPosition {
+                    symbol: Some(HoldingsSymbol {
+                        symbol: Some(HoldingsInnerSymbol {
+                            symbol: Some("AAPL".to_string()),
+                            ..Default::default()
+                        }),
+                        ..Default::default()
+                    }),
+                    units: Some(10.0),
+                    average_purchase_price: Some(150.0),
+                    ..Default::default()
+                }]),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let computed = [ComputedPosition {
+            symbol: "AAPL".to_string(),
+            quantity: Decimal::new(10_004, 3),
+            average_cost: Decimal::new(150_004, 3),
+        }];
+
+        let strict = orchestrator(Arc::new(MockSyncService::default()), SyncConfig::default())
+            .reconcile_account_holdings(&api, "acc", "broker-acc", &computed)
+            .await
+            .unwrap();
+        assert_eq!(strict.account_id, "acc");
+        assert_eq!(strict.discrepancies.len(), 1);
+        assert_eq!(strict.discrepancies[0].symbol, "AAPL");
+
+        // A looser cost tolerance does not hide the quantity difference
+        let config = SyncConfig::builder()
+            .reconciliation_cost_tolerance(Decimal::ONE)
+            .build()
+            .unwrap();
+        let loose_cost = orchestrator(Arc::new(MockSyncService::default()), config)
+            .reconcile_account_holdings(&api, "acc", "broker-acc", &computed)
+            .await
+            .unwrap();
+        assert_eq!(loose_cost.discrepancies.len(), 1);
+
+        let config = SyncConfig::builder()
+            .reconciliation_quantity_tolerance(Decimal::new(1, 2))
+            .build()
+            .unwrap();
+        let lenient = orchestrator(Arc::new(MockSyncService::default()), config)
+            .reconcile_account_holdings(&api, "acc", "broker-acc", &computed)
+            .await
+            .unwrap();
+        assert!(lenient.is_reconciled());
+        assert_eq!(api.holdings_calls.load(Ordering::SeqCst), 3);
+    }
 }
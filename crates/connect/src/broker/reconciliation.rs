@@ -0,0 +1,259 @@
+//! Reconciliation of locally computed positions against broker holdings.
+//!
+//! After activities are imported, the positions computed from them can drift
+//! from what the broker reports. This module only reports the differences;
+//! nothing is corrected automatically.
+
+use std::collections::BTreeMap;
+
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use super::models::{BrokerHoldingsResponse, HoldingsPosition};
+
+/// A position computed locally from imported activities.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComputedPosition {
+    pub symbol: String,
+    pub quantity: Decimal,
+    pub average_cost: Decimal,
+}
+
+/// A symbol whose computed position differs from the broker's.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionDiscrepancy {
+    pub symbol: String,
+    /// Quantity computed from activities (zero if the symbol is not held locally)
+    pub computed_quantity: Decimal,
+    /// Quantity reported by the broker (zero if the broker does not report it)
+    pub broker_quantity: Decimal,
+    pub computed_average_cost: Option<Decimal>,
+    pub broker_average_cost: Option<Decimal>,
+}
+
+/// Per-symbol differences between computed positions and broker holdings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReconciliationReport {
+    pub account_id: String,
+    pub discrepancies: Vec<PositionDiscrepancy>,
+}
+
+impl ReconciliationReport {
+    /// True when no discrepancies were found.
+    pub fn is_reconciled(&self) -> bool {
+        self.discrepancies.is_empty()
+    }
+}
+
+/// Symbol used to match a broker position, preferring the API symbol.
+fn position_symbol(position: &HoldingsPosition) -> Option<String> {
+    let inner = position.symbol.as_ref()?.symbol.as_ref()?;
+    inner
+        .symbol
+        .as_deref()
+        .or(inner.raw_symbol.as_deref())
+        .map(|s| s.trim().to_uppercase())
+        .filter(|s| !s.is_empty())
+}
+
+/// Compare computed positions with the broker's holdings.
+///
+/// Quantities are considered equal when they differ by no more than
+/// `quantity_tolerance` (in shares), average costs when they differ by no more
+/// than `cost_tolerance` (in the position currency). Average costs are only
+/// compared when both sides report one.
+pub fn reconcile_holdings(
+    account_id: &str,
+    computed: &[ComputedPosition],
+    holdings: &BrokerHoldingsResponse,
+    quantity_tolerance: Decimal,
+    cost_tolerance: Decimal,
+) -> ReconciliationReport {
+    // symbol -> (quantity, average cost)
+    let mut broker: BTreeMap<String, (Decimal, Option<Decimal>)> = BTreeMap::new();
+    for position in holdings.positions.iter().flatten() {
+        let Some(symbol) = position_symbol(position) else {
+            continue;
+        };
+        let units = position
+            .units
+            .and_then(Decimal::from_f64)
+            .unwrap_or(Decimal::ZERO);
+        let entry = broker.entry(symbol).or_insert((Decimal::ZERO, None));
+        entry.0 += units;
+        if entry.1.is_none() {
+            entry.1 = position.average_purchase_price.and_then(Decimal::from_f64);
+        }
+    }
+
+    let mut local: BTreeMap<String, (Decimal, Decimal)> = BTreeMap::new();
+    for position in computed {
+        let entry = local
+            .entry(position.symbol.trim().to_uppercase())
+            .or_insert((Decimal::ZERO, position.average_cost));
+        entry.0 += position.quantity;
+    }
+
+    let mut symbols: Vec<&String> = broker.keys().chain(local.keys()).collect();
+    symbols.sort();
+    symbols.dedup();
+
+    let discrepancies = symbols
+        .into_iter()
+        .filter_map(|symbol| {
+            let (broker_quantity, broker_average_cost) =
+                broker.get(symbol).copied().unwrap_or((Decimal::ZERO, None));
+            let (computed_quantity, computed_average_cost) = match local.get(symbol) {
+                Some((quantity, cost)) => (*quantity, Some(*cost)),
+                None => (Decimal::ZERO, None),
+            };
+
+            let quantity_differs = (computed_quantity - broker_quantity).abs() > quantity_tolerance;
+            let cost_differs = match (computed_average_cost, broker_average_cost) {
+                (Some(computed), Some(broker)) => (computed - broker).abs() > cost_tolerance,
+                _ => false,
+            };
+
+            (quantity_differs || cost_differs).then(|| PositionDiscrepancy {
+                symbol: symbol.clone(),
+                computed_quantity,
+                broker_quantity,
+                computed_average_cost,
+                broker_average_cost,
+            })
+        })
+        .collect();
+
+    ReconciliationReport {
+        account_id: account_id.to_string(),
+        discrepancies,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::broker::models::{HoldingsInnerSymbol, HoldingsSymbol};
+
+    fn broker_position(symbol: &str, units: f64, average_cost: f64) -> HoldingsPosition {
+        HoldingsPosition {
+            symbol: Some(HoldingsSymbol {
+                symbol: Some(HoldingsInnerSymbol {
+                    symbol: Some(symbol.to_string()),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            }),
+            units: Some(units),
+            average_purchase_price: Some(average_cost),
+            ..Default::default()
+        }
+    }
+
+    fn computed(symbol: &str, quantity: i64, average_cost: i64) -> ComputedPosition {
+        ComputedPosition {
+            symbol: symbol.to_string(),
+            quantity: Decimal::from(quantity),
+            average_cost: Decimal::from(average_cost),
+        }
+    }
+
+    #[test]
+    fn test_reconcile_matching_positions() {
+        let holdings = BrokerHoldingsResponse {
+            positions: Some(vec![broker_position("AAPL", 10.0, 150.0)]),
+            ..Default::default()
+        };
+
+        let report = reconcile_holdings(
+            "acc",
+            &[computed("aapl", 10, 150)],
+            &holdings,
+            Decimal::new(1, 6),
+            Decimal::new(1, 6),
+        );
+        assert!(report.is_reconciled());
+    }
+
+    #[test]
+    fn test_reconcile_reports_quantity_cost_and_missing_symbols() {
+        let holdings = BrokerHoldingsResponse {
+            positions: Some(vec![
+                broker_position("AAPL", 12.0, 150.0),
+                broker_position("MSFT", 5.0, 300.0),
+                broker_position("TSLA", 3.0, 200.0),
+            ]),
+            ..Default::default()
+        };
+
+        let report = reconcile_holdings(
+            "acc",
+            &[
+                computed("AAPL", 10, 150),
+                computed("MSFT", 5, 280),
+                computed("NVDA", 4, 400),
+            ],
+            &holdings,
+            Decimal::new(1, 6),
+            Decimal::new(1, 6),
+        );
+
+        let symbols: Vec<&str> = report
+            .discrepancies
+            .iter()
+            .map(|d| d.symbol.as_str())
+            .collect();
+        assert_eq!(symbols, vec!["AAPL", "MSFT", "NVDA", "TSLA"]);
+
+        let aapl = &report.discrepancies[0];
+        assert_eq!(aapl.computed_quantity, Decimal::from(10));
+        assert_eq!(aapl.broker_quantity, Decimal::from(12));
+
+        let nvda = &report.discrepancies[2];
+        assert_eq!(nvda.broker_quantity, Decimal::ZERO);
+        assert_eq!(nvda.broker_average_cost, None);
+
+        let tsla = &report.discrepancies[3];
+        assert_eq!(tsla.computed_quantity, Decimal::ZERO);
+        assert_eq!(tsla.computed_average_cost, None);
+    }
+
+    #[test]
+    fn test_reconcile_uses_separate_quantity_and_cost_tolerances() {
+        let holdings = BrokerHoldingsResponse {
+            positions: Some(vec![broker_position("AAPL", 10.5, 150.004)]),
+            ..Default::default()
+        };
+
+        // A cost tolerance loose enough for price rounding must not hide a
+        // missing half share.
+        let report = reconcile_holdings(
+            "acc",
+            &[computed("AAPL", 10, 150)],
+            &holdings,
+            Decimal::new(1, 6),
+            Decimal::new(1, 2),
+        );
+        assert_eq!(report.discrepancies.len(), 1);
+        assert_eq!(
+            report.discrepancies[0].broker_quantity,
+            Decimal::new(105, 1)
+        );
+
+        let holdings = BrokerHoldingsResponse {
+            positions: Some(vec![broker_position("AAPL", 10.0, 150.004)]),
+            ..Default::default()
+        };
+        let report = reconcile_holdings(
+            "acc",
+            &[computed("AAPL", 10, 150)],
+            &holdings,
+            Decimal::new(1, 6),
+            Decimal::new(1, 2),
+        );
+        assert!(report.is_reconciled());
+    }
+}
@@ -6,8 +6,9 @@ use std::sync::Arc;
 
 use super::mapping;
 use super::models::{
-    AccountUniversalActivity, BrokerAccount, BrokerConnection, HoldingsBalance, HoldingsPosition,
-    NewAccountInfo, SyncAccountsResponse, SyncConnectionsResponse,
+    total_cash_by_currency, AccountUniversalActivity, BrokerAccount, BrokerConnection,
+    HoldingsBalance, HoldingsPosition, NewAccountInfo, SyncAccountsResponse,
+    SyncConnectionsResponse, UpsertActivitiesResult,
 };
 use super::traits::BrokerSyncServiceTrait;
 use crate::platform::{Platform, PlatformRepository};
@@ -31,7 +32,8 @@ use wealthfolio_core::portfolio::snapshot::{
     AccountStateSnapshot, Position, SnapshotRepositoryTrait, SnapshotServiceTrait, SnapshotSource,
 };
 use wealthfolio_core::sync::{
-    ImportRun, ImportRunMode, ImportRunStatus, ImportRunSummary, ImportRunType, ReviewMode,
+    BrokerActivitiesCheckpoint, ImportRun, ImportRunMode, ImportRunStatus, ImportRunSummary,
+    ImportRunType, ReviewMode,
 };
 use wealthfolio_core::utils::time_utils::valuation_date_today;
 use wealthfolio_storage_sqlite::activities::ActivityRepository;
@@ -115,8 +117,15 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
     ) -> Result<SyncConnectionsResponse> {
         let mut platforms_created = 0;
         let mut platforms_updated = 0;
+        let mut synced_connection_ids = Vec::new();
+        // Platform IDs of the connections the backend still reports
+        let mut connected_platform_ids: HashSet<String> = HashSet::new();
+        let mut all_connections_resolved = true;
 
         for connection in &connections {
+            if connection.brokerage.is_none() {
+                all_connections_resolved = false;
+            }
             if let Some(brokerage) = &connection.brokerage {
                 // Use slug as the platform ID, fall back to UUID if no slug
                 let platform_id = brokerage
@@ -129,8 +138,10 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
                         "Skipping connection with no brokerage slug or id: {:?}",
                         connection.id
                     );
+                    all_connections_resolved = false;
                     continue;
                 }
+                connected_platform_ids.insert(platform_id.clone());
 
                 // Check if platform already exists
                 let existing = self.platform_repository.get_by_id(&platform_id)?;
@@ -152,6 +163,7 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
                 };
 
                 self.platform_repository.upsert(platform).await?;
+                synced_connection_ids.push(connection.id.clone());
 
                 if existing.is_some() {
                     platforms_updated += 1;
@@ -163,10 +175,39 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
             }
         }
 
+        // Remove platforms whose connections were deleted on the backend. Skipped when
+        // a connection could not be matched to a platform, to avoid pruning its platform.
+        let mut removed_platform_ids = Vec::new();
+        if all_connections_resolved {
+            let referenced_platform_ids: HashSet<String> = self
+                .account_service
+                .get_all_accounts()?
+                .into_iter()
+                .filter_map(|a| a.platform_id)
+                .collect();
+            let platforms = self.platform_repository.list()?;
+
+            for platform in Self::stale_connect_platforms(&platforms, &connected_platform_ids) {
+                if referenced_platform_ids.contains(&platform.id) {
+                    info!(
+                        "Platform {} is no longer connected but is still used by accounts",
+                        platform.id
+                    );
+                    continue;
+                }
+                self.platform_repository.delete(&platform.id).await?;
+                removed_platform_ids.push(platform.id.clone());
+                info!("Removed platform for deleted connection: {}", platform.id);
+            }
+        }
+
         Ok(SyncConnectionsResponse {
             synced: connections.len(),
             platforms_created,
             platforms_updated,
+            platforms_removed: removed_platform_ids.len(),
+            synced_connection_ids,
+            removed_platform_ids,
         })
     }
 
@@ -296,14 +337,29 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
             .await
     }
 
+    async fn save_activity_sync_checkpoint(
+        &self,
+        account_id: String,
+        checkpoint: Option<BrokerActivitiesCheckpoint>,
+    ) -> Result<()> {
+        let checkpoint_json = checkpoint.map(serde_json::to_value).transpose()?;
+        self.brokers_sync_state_repository
+            .upsert_checkpoint(
+                account_id,
+                DEFAULT_BROKERAGE_PROVIDER.to_string(),
+                checkpoint_json,
+            )
+            .await
+    }
+
     async fn upsert_account_activities(
         &self,
         account_id: String,
         import_run_id: Option<String>,
         activities_data: Vec<AccountUniversalActivity>,
-    ) -> Result<(usize, usize, Vec<String>, usize)> {
+    ) -> Result<UpsertActivitiesResult> {
         if activities_data.is_empty() {
-            return Ok((0, 0, Vec::new(), 0));
+            return Ok(UpsertActivitiesResult::default());
         }
 
         let account = self.account_service.get_account(&account_id)?;
@@ -336,7 +392,7 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
         }
 
         if new_activities.is_empty() {
-            return Ok((0, 0, Vec::new(), 0));
+            return Ok(UpsertActivitiesResult::default());
         }
 
         // 2. Use prepare_activities for asset creation + FX registration
@@ -421,7 +477,6 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
             .activity_service
             .upsert_activities_bulk(activity_upserts)
             .await?;
-        let activities_upserted = bulk_result.upserted;
 
         debug!(
             "Upserted {} activities for account {} ({} assets created, {} new asset IDs, {} need review)",
@@ -432,12 +487,13 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
             needs_review_count
         );
 
-        Ok((
-            activities_upserted,
-            assets_created,
+        Ok(UpsertActivitiesResult {
+            activities_upserted: bulk_result.upserted,
+            assets_inserted: assets_created,
             new_asset_ids,
             needs_review_count,
-        ))
+            written_activity_ids: bulk_result.written_ids,
+        })
     }
 
     async fn finalize_activity_sync_success(
@@ -550,16 +606,7 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
         let now = chrono::Utc::now();
 
         // Build cash balances HashMap
-        let mut cash_balances: HashMap<String, Decimal> = HashMap::new();
-        for balance in &balances {
-            if let (Some(currency), Some(cash)) = (
-                balance.currency.as_ref().and_then(|c| c.code.clone()),
-                balance.cash,
-            ) {
-                let cash_decimal = Decimal::from_f64(cash).unwrap_or(Decimal::ZERO);
-                *cash_balances.entry(currency).or_insert(Decimal::ZERO) += cash_decimal;
-            }
-        }
+        let cash_balances = total_cash_by_currency(&balances);
 
         // 1. Build AssetSpecs and position data from broker positions
         let mut asset_specs: Vec<AssetSpec> = Vec::new();
@@ -827,6 +874,21 @@ impl BrokerSyncServiceTrait for BrokerSyncService {
 }
 
 impl BrokerSyncService {
+    /// Platforms created from Connect brokerages that no current connection uses.
+    fn stale_connect_platforms<'a>(
+        platforms: &'a [Platform],
+        connected_platform_ids: &HashSet<String>,
+    ) -> Vec<&'a Platform> {
+        platforms
+            .iter()
+            .filter(|p| {
+                p.external_id.is_some()
+                    && p.kind == "BROKERAGE"
+                    && !connected_platform_ids.contains(&p.id)
+            })
+            .collect()
+    }
+
     fn normalize_holdings_symbol(
         raw_symbol: Option<&str>,
         api_symbol: Option<&str>,
@@ -1081,6 +1143,32 @@ impl BrokerSyncService {
 #[cfg(test)]
 mod tests {
     use super::BrokerSyncService;
+    use crate::platform::Platform;
+    use std::collections::HashSet;
+
+    #[test]
+    fn stale_connect_platforms_finds_platforms_of_removed_connections() {
+        let platform = |id: &str, external_id: Option<&str>| Platform {
+            id: id.to_string(),
+            external_id: external_id.map(str::to_string),
+            kind: "BROKERAGE".to_string(),
+            ..Default::default()
+        };
+        let platforms = vec![
+            platform("QUESTRADE", Some("uuid-1")),
+            platform("WEALTHSIMPLE", Some("uuid-2")),
+            // Created manually, never pruned
+            platform("LOCAL_BANK", None),
+        ];
+        let connected = HashSet::from(["QUESTRADE".to_string()]);
+
+        let stale: Vec<&str> = BrokerSyncService::stale_connect_platforms(&platforms, &connected)
+            .into_iter()
+            .map(|p| p.id.as_str())
+            .collect();
+
+        assert_eq!(stale, vec!["WEALTHSIMPLE"]);
+    }
 
     #[test]
     fn normalize_holdings_symbol_uses_api_suffix_when_raw_has_no_suffix() {
@@ -0,0 +1,403 @@
+#[cfg(test)]
+mod tests {
+    use crate::broker::models::BrokerConnection;
+    use crate::broker::service::BrokerSyncService;
+    use crate::broker::traits::BrokerSyncServiceTrait;
+    use crate::platform::{Platform, PlatformRepository};
+    use async_trait::async_trait;
+    use chrono::{DateTime, NaiveDate, Utc};
+    use std::collections::HashMap;
+    use std::sync::Arc;
+    use tempfile::TempDir;
+    use wealthfolio_core::accounts::{Account, AccountServiceTrait, AccountUpdate, NewAccount};
+    use wealthfolio_core::activities::{
+        Activity, ActivityBulkMutationRequest, ActivityBulkMutationResult, ActivityImport,
+        ActivityRepositoryTrait, ActivitySearchResponse, ActivityServiceTrait, ActivityUpdate,
+        ActivityUpsert, BulkUpsertResult, ImportActivitiesResult, ImportMappingData, NewActivity,
+        ParseConfig, ParsedCsvResult, PrepareActivitiesResult, Sort,
+    };
+    use wealthfolio_core::assets::{
+        Asset, AssetMetadata, AssetServiceTrait, AssetSpec, EnsureAssetsResult, NewAsset,
+        UpdateAssetProfile,
+    };
+    use wealthfolio_core::errors::Result;
+    use wealthfolio_storage_sqlite::db::{create_pool, run_migrations, write_actor::spawn_writer};
+
+    // --- Mock AccountService ---
+    struct MockAccountService {
+        accounts: Vec<Account>,
+    }
+
+    #[async_trait]
+    impl AccountServiceTrait for MockAccountService {
+        async fn create_account(&self, _new_account: NewAccount) -> Result<Account> {
+            unimplemented!()
+        }
+
+        async fn update_account(&self, _account_update: AccountUpdate) -> Result<Account> {
+            unimplemented!()
+        }
+
+        async fn delete_account(&self, _account_id: &str) -> Result<()> {
+            unimplemented!()
+        }
+
+        fn get_account(&self, _account_id: &str) -> Result<Account> {
+            unimplemented!()
+        }
+
+        fn list_accounts(
+            &self,
+            _is_active_filter: Option<bool>,
+            _is_archived_filter: Option<bool>,
+            _account_ids: Option<&[String]>,
+        ) -> Result<Vec<Account>> {
+            unimplemented!()
+        }
+
+        fn get_all_accounts(&self) -> Result<Vec<Account>> {
+            Ok(self.accounts.clone())
+        }
+
+        fn get_active_accounts(&self) -> Result<Vec<Account>> {
+            unimplemented!()
+        }
+
+        fn get_accounts_by_ids(&self, _account_ids: &[String]) -> Result<Vec<Account>> {
+            unimplemented!()
+        }
+
+        fn get_non_archived_accounts(&self) -> Result<Vec<Account>> {
+            unimplemented!()
+        }
+
+        fn get_active_non_archived_accounts(&self) -> Result<Vec<Account>> {
+            unimplemented!()
+        }
+
+        fn get_base_currency(&self) -> Option<String> {
+            None
+        }
+    }
+
+    // --- Mock AssetService (unused by connection sync) ---
+    struct MockAssetService;
+
+    #[async_trait]
+    impl AssetServiceTrait for MockAssetService {
+        fn get_assets(&self) -> Result<Vec<Asset>> {
+            unimplemented!()
+        }
+
+        fn get_asset_by_id(&self, _asset_id: &str) -> Result<Asset> {
+            unimplemented!()
+        }
+
+        async fn delete_asset(&self, _asset_id: &str) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn update_asset_profile(
+            &self,
+            _asset_id: &str,
+            _payload: UpdateAssetProfile,
+        ) -> Result<Asset> {
+            unimplemented!()
+        }
+
+        async fn create_asset(&self, _new_asset: NewAsset) -> Result<Asset> {
+            unimplemented!()
+        }
+
+        async fn get_or_create_minimal_asset(
+            &self,
+            _asset_id: &str,
+            _context_currency: Option<String>,
+            _metadata: Option<AssetMetadata>,
+            _quote_mode_hint: Option<String>,
+        ) -> Result<Asset> {
+            unimplemented!()
+        }
+
+        async fn update_quote_mode(&self, _asset_id: &str, _quote_mode: &str) -> Result<Asset> {
+            unimplemented!()
+        }
+
+        async fn get_assets_by_asset_ids(&self, _asset_ids: &[String]) -> Result<Vec<Asset>> {
+            unimplemented!()
+        }
+
+        async fn enrich_asset_profile(&self, _asset_id: &str) -> Result<Asset> {
+            unimplemented!()
+        }
+
+        async fn enrich_assets(&self, _asset_ids: Vec<String>) -> Result<(usize, usize, usize)> {
+            unimplemented!()
+        }
+
+        async fn cleanup_legacy_metadata(&self, _asset_id: &str) -> Result<()> {
+            unimplemented!()
+        }
+
+        async fn merge_unknown_asset(
+            &self,
+            _resolved_asset_id: &str,
+            _unknown_asset_id: &str,
+            _activity_repository: &dyn ActivityRepositoryTrait,
+        ) -> Result<u32> {
+            unimplemented!()
+        }
+
+        async fn ensure_assets(
+            &self,
+            _specs: Vec<AssetSpec>,
+            _activity_repository: &dyn ActivityRepositoryTrait,
+        ) -> Result<EnsureAssetsResult> {
+            unimplemented!()
+        }
+    }
+
+    // --- Mock ActivityService (unused by connection sync) ---
+    struct MockActivityService;
+
+    #[async_trait]
+    impl ActivityServiceTrait for MockActivityService {
+        fn get_activity(&self, _activity_id: &str) -> Result<Activity> {
+            unimplemented!()
+        }
+
+        fn get_activities(&self) -> Result<Vec<Activity>> {
+            unimplemented!()
+        }
+
+        fn get_activities_by_account_id(&self, _account_id: &str) -> Result<Vec<Activity>> {
+            unimplemented!()
+        }
+
+        fn get_activities_by_account_ids(&self, _account_ids: &[String]) -> Result<Vec<Activity>> {
+            unimplemented!()
+        }
+
+        fn get_trading_activities(&self) -> Result<Vec<Activity>> {
+            unimplemented!()
+        }
+
+        fn get_income_activities(&self) -> Result<Vec<Activity>> {
+            unimplemented!()
+        }
+
+        fn search_activities(
+            &self,
+            _page: i64,
+            _page_size: i64,
+            _account_id_filter: Option<Vec<String>>,
+            _activity_type_filter: Option<Vec<String>>,
+            _asset_id_keyword: Option<String>,
+            _sort: Option<Sort>,
+            _needs_review_filter: Option<bool>,
+            _date_from: Option<NaiveDate>,
+            _date_to: Option<NaiveDate>,
+        ) -> Result<ActivitySearchResponse> {
+            unimplemented!()
+        }
+
+        fn get_first_activity_date(
+            &self,
+            _account_ids: Option<&[String]>,
+        ) -> Result<Option<DateTime<Utc>>> {
+            unimplemented!()
+        }
+
+        fn get_import_mapping(&self, _account_id: String) -> Result<ImportMappingData> {
+            unimplemented!()
+        }
+
+        async fn create_activity(&self, _activity: NewActivity) -> Result<Activity> {
+            unimplemented!()
+        }
+
+        async fn update_activity(&self, _activity: ActivityUpdate) -> Result<Activity> {
+            unimplemented!()
+        }
+
+        async fn delete_activity(&self, _activity_id: String) -> Result<Activity> {
+            unimplemented!()
+        }
+
+        async fn bulk_mutate_activities(
+            &self,
+            _request: ActivityBulkMutationRequest,
+        ) -> Result<ActivityBulkMutationResult> {
+            unimplemented!()
+        }
+
+        async fn check_activities_import(
+            &self,
+            _account_id: String,
+            _activities: Vec<ActivityImport>,
+        ) -> Result<Vec<ActivityImport>> {
+            unimplemented!()
+        }
+
+        async fn import_activities(
+            &self,
+            _account_id: String,
+            _activities: Vec<ActivityImport>,
+        ) -> Result<ImportActivitiesResult> {
+            unimplemented!()
+        }
+
+        async fn save_import_mapping(
+            &self,
+            _mapping_data: ImportMappingData,
+        ) -> Result<ImportMappingData> {
+            unimplemented!()
+        }
+
+        fn check_existing_duplicates(
+            &self,
+            _idempotency_keys: Vec<String>,
+        ) -> Result<HashMap<String, String>> {
+            unimplemented!()
+        }
+
+        fn parse_csv(&self, _content: &[u8], _config: &ParseConfig) -> Result<ParsedCsvResult> {
+            unimplemented!()
+        }
+
+        async fn upsert_activities_bulk(
+            &self,
+            _activities: Vec<ActivityUpsert>,
+        ) -> Result<BulkUpsertResult> {
+            unimplemented!()
+        }
+
+        async fn prepare_activities(
+            &self,
+            _activities: Vec<NewActivity>,
+            _account: &Account,
+        ) -> Result<PrepareActivitiesResult> {
+            unimplemented!()
+        }
+    }
+
+    // --- Helpers ---
+
+    /// Service over a temporary database seeded with `platforms`, with
+    /// `accounts` reported by the account service.
+    async fn create_test_service(
+        platforms: Vec<Platform>,
+        accounts: Vec<Account>,
+    ) -> (BrokerSyncService, Arc<PlatformRepository>, TempDir) {
+        let temp_dir = tempfile::tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("test.db");
+        let db_path_str = db_path.to_string_lossy().to_string();
+
+        run_migrations(&db_path_str).expect("Failed to run migrations");
+        let pool = create_pool(&db_path_str).expect("Failed to create pool");
+        let writer = spawn_writer((*pool).clone());
+
+        let platform_repository = Arc::new(PlatformRepository::new(pool.clone(), writer.clone()));
+        for platform in platforms {
+            platform_repository
+                .upsert(platform)
+                .await
+                .expect("Failed to seed platform");
+        }
+
+        let service = BrokerSyncService::new(
+            Arc::new(MockAccountService { accounts }),
+            Arc::new(MockAssetService),
+            Arc::new(MockActivityService),
+            platform_repository.clone(),
+            pool,
+            writer,
+        );
+        (service, platform_repository, temp_dir)
+    }
+
+    fn connect_platform(id: &str) -> Platform {
+        Platform {
+            id: id.to_string(),
+            url: format!("https://{}.com", id.to_lowercase()),
+            external_id: Some(format!("uuid-{}", id.to_lowercase())),
+            kind: "BROKERAGE".to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn connection(id: &str, brokerage_slug: Option<&str>) -> BrokerConnection {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "brokerage": brokerage_slug.map(|slug| serde_json::json!({
+                "id": format!("uuid-{}", slug.to_lowercase()),
+                "slug": slug,
+            })),
+        }))
+        .unwrap()
+    }
+
+    fn platform_ids(repository: &PlatformRepository) -> Vec<String> {
+        let mut ids: Vec<String> = repository
+            .list()
+            .unwrap()
+            .into_iter()
+            .map(|p| p.id)
+            .collect();
+        ids.sort();
+        ids
+    }
+
+    #[tokio::test]
+    async fn test_sync_connections_prunes_platform_of_removed_connection() {
+        let (service, platforms, _temp_dir) = create_test_service(
+            vec![
+                connect_platform("QUESTRADE"),
+                connect_platform("WEALTHSIMPLE"),
+                connect_platform("ROBINHOOD"),
+            ],
+            // An account still uses the ROBINHOOD platform
+            vec![Account {
+                id: "acc".to_string(),
+                platform_id: Some("ROBINHOOD".to_string()),
+                ..Default::default()
+            }],
+        )
+        .await;
+
+        let response = service
+            .sync_connections(vec![connection("c1", Some("QUESTRADE"))])
+            .await
+            .unwrap();
+
+        assert_eq!(response.platforms_updated, 1);
+        assert_eq!(response.platforms_removed, 1);
+        assert_eq!(response.removed_platform_ids, vec!["WEALTHSIMPLE"]);
+        assert_eq!(platform_ids(&platforms), vec!["QUESTRADE", "ROBINHOOD"]);
+    }
+
+    #[tokio::test]
+    async fn test_sync_connections_skips_pruning_when_a_connection_is_unresolved() {
+        let (service, platforms, _temp_dir) = create_test_service(
+            vec![
+                connect_platform("QUESTRADE"),
+                connect_platform("WEALTHSIMPLE"),
+            ],
+            Vec::new(),
+        )
+        .await;
+
+        let response = service
+            .sync_connections(vec![
+                connection("c1", Some("QUESTRADE")),
+                // No brokerage: could be the WEALTHSIMPLE connection
+                connection("c2", None),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(response.platforms_removed, 0);
+        assert_eq!(response.synced_connection_ids, vec!["c1"]);
+        assert_eq!(platform_ids(&platforms), vec!["QUESTRADE", "WEALTHSIMPLE"]);
+    }
+}
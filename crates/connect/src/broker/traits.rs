@@ -5,13 +5,15 @@ use async_trait::async_trait;
 use super::models::{
     AccountUniversalActivity, BrokerAccount, BrokerBrokerage, BrokerConnection,
     BrokerHoldingsResponse, HoldingsBalance, HoldingsPosition, PaginatedUniversalActivity,
-    SyncAccountsResponse, SyncConnectionsResponse,
+    SyncAccountsResponse, SyncConnectionsResponse, UpsertActivitiesResult,
 };
 use crate::platform::Platform;
 use crate::state::BrokerSyncState;
 use wealthfolio_core::accounts::Account;
 use wealthfolio_core::errors::Result;
-use wealthfolio_core::sync::{ImportRun, ImportRunMode, ImportRunStatus, ImportRunSummary};
+use wealthfolio_core::sync::{
+    BrokerActivitiesCheckpoint, ImportRun, ImportRunMode, ImportRunStatus, ImportRunSummary,
+};
 
 /// Trait for fetching data from the cloud broker API
 #[async_trait]
@@ -93,14 +95,21 @@ pub trait BrokerSyncServiceTrait: Send + Sync {
     /// Record an activity sync attempt for an account.
     async fn mark_activity_sync_attempt(&self, account_id: String) -> Result<()>;
 
+    /// Store the pagination checkpoint of an in-progress activity sync.
+    /// Passing `None` clears it.
+    async fn save_activity_sync_checkpoint(
+        &self,
+        account_id: String,
+        checkpoint: Option<BrokerActivitiesCheckpoint>,
+    ) -> Result<()>;
+
     /// Upsert a batch/page of broker activities for a local account.
-    /// Returns (activities_upserted, assets_inserted, new_asset_ids, needs_review_count).
     async fn upsert_account_activities(
         &self,
         account_id: String,
         import_run_id: Option<String>,
         activities: Vec<AccountUniversalActivity>,
-    ) -> Result<(usize, usize, Vec<String>, usize)>;
+    ) -> Result<UpsertActivitiesResult>;
 
     /// Finalize an activity sync as successful for an account.
     async fn finalize_activity_sync_success(
@@ -12,7 +12,8 @@ use std::time::Duration;
 
 use crate::broker::{
     BrokerAccount, BrokerBrokerage, BrokerConnection, BrokerConnectionBrokerage,
-    BrokerHoldingsResponse, PaginatedUniversalActivity, PlansResponse, UserInfo, UserTeam,
+    BrokerHoldingsResponse, PaginatedUniversalActivity, PaginationDetails, PlansResponse, UserInfo,
+    UserTeam,
 };
 use wealthfolio_core::errors::{Error, Result};
 
@@ -24,6 +25,12 @@ const DEFAULT_TIMEOUT_SECS: u64 = 30;
 /// Default base URL for Wealthfolio Connect cloud service.
 pub const DEFAULT_CLOUD_API_URL: &str = "https://api-staging.wealthfolio.app";
 
+/// Page size requested when listing broker connections.
+const CONNECTIONS_PAGE_LIMIT: i64 = 100;
+
+/// Maximum number of connection pages followed before giving up (safety limit).
+const MAX_CONNECTION_PAGES: usize = 100;
+
 // ─────────────────────────────────────────────────────────────────────────────
 // API Response Types (internal, for parsing cloud API responses)
 // ─────────────────────────────────────────────────────────────────────────────
@@ -32,6 +39,8 @@ pub const DEFAULT_CLOUD_API_URL: &str = "https://api-staging.wealthfolio.app";
 struct ApiConnectionsResponse {
     #[serde(default)]
     connections: Vec<ApiConnection>,
+    #[serde(default)]
+    pagination: Option<PaginationDetails>,
 }
 
 #[allow(dead_code)]
@@ -44,6 +53,7 @@ struct ApiConnection {
     brokerage_slug: Option<String>,
     brokerage: Option<ApiBrokerage>,
     disabled: Option<bool>,
+    disabled_reason: Option<String>,
     updated_at: Option<String>,
     name: Option<String>,
     status: Option<String>,
@@ -231,6 +241,49 @@ impl ConnectApiClient {
     // Brokerage Endpoints
     // ─────────────────────────────────────────────────────────────────────────
 
+    /// Fetch one page of broker connections.
+    async fn list_connections_page(
+        &self,
+        offset: i64,
+        limit: i64,
+    ) -> Result<ApiConnectionsResponse> {
+        let url = format!(
+            "{}/api/v1/sync/brokerage/connections?offset={}&limit={}",
+            self.base_url, offset, limit
+        );
+        let response = self
+            .client
+            .get(&url)
+            .headers(self.headers())
+            .send()
+            .await
+            .map_err(|e| Error::Unexpected(format!("Request failed: {}", e)))?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .map_err(|e| Error::Unexpected(format!("Failed to read response: {}", e)))?;
+
+        // Log raw connections payload at INFO level for debugging
+        info!(
+            "[ConnectApi] Raw connections API response (status={}, offset={}): {}",
+            status, offset, &body
+        );
+
+        if !status.is_success() {
+            return Err(Error::Unexpected(format!(
+                "API error {}: {}",
+                status,
+                body.chars().take(200).collect::<String>()
+            )));
+        }
+
+        serde_json::from_str(&body).map_err(|e| {
+            Error::Unexpected(format!("Failed to parse connections: {} - {}", e, body))
+        })
+    }
+
     /// Fetch account activities with pagination.
     ///
     /// # Arguments
@@ -380,44 +433,58 @@ impl ConnectApiClient {
 
 #[async_trait]
 impl BrokerApiClient for ConnectApiClient {
-    /// Fetch all broker connections for the user.
+    /// Fetch all broker connections for the user, following pagination.
     async fn list_connections(&self) -> Result<Vec<BrokerConnection>> {
-        // First, get the raw response to log it
-        let url = format!("{}/api/v1/sync/brokerage/connections", self.base_url);
-        let response = self
-            .client
-            .get(&url)
-            .headers(self.headers())
-            .send()
-            .await
-            .map_err(|e| Error::Unexpected(format!("Request failed: {}", e)))?;
-
-        let status = response.status();
-        let body = response
-            .text()
-            .await
-            .map_err(|e| Error::Unexpected(format!("Failed to read response: {}", e)))?;
+        let mut api_connections: Vec<ApiConnection> = Vec::new();
+        let mut offset: i64 = 0;
+        let mut pages_fetched: usize = 0;
+
+        loop {
+            if pages_fetched >= MAX_CONNECTION_PAGES {
+                return Err(Error::Unexpected(format!(
+                    "Connections pagination exceeded max pages ({})",
+                    MAX_CONNECTION_PAGES
+                )));
+            }
 
-        // Log raw connections payload at INFO level for debugging
-        info!(
-            "[ConnectApi] Raw connections API response (status={}): {}",
-            status, &body
-        );
+            let page = self
+                .list_connections_page(offset, CONNECTIONS_PAGE_LIMIT)
+                .await?;
+            pages_fetched += 1;
+
+            // A backend that ignores `offset` keeps serving the first page;
+            // fail early rather than collecting duplicates up to MAX_CONNECTION_PAGES.
+            let reported_offset = page.pagination.as_ref().and_then(|p| p.offset);
+            let repeated_page = page
+                .connections
+                .first()
+                .is_some_and(|first| api_connections.iter().any(|c| c.id == first.id));
+            if offset > 0 && (repeated_page || reported_offset.is_some_and(|o| o < offset)) {
+                return Err(Error::Unexpected(format!(
+                    "Connections pagination did not advance past offset {}",
+                    offset
+                )));
+            }
 
-        if !status.is_success() {
-            return Err(Error::Unexpected(format!(
-                "API error {}: {}",
-                status,
-                body.chars().take(200).collect::<String>()
-            )));
+            let received = page.connections.len() as i64;
+            offset += received;
+            api_connections.extend(page.connections);
+
+            // Without pagination details, a full page may have been cut off at
+            // the limit, so keep going until a short page comes back.
+            let has_more = match page.pagination {
+                Some(p) => match p.has_more {
+                    Some(has_more) => has_more,
+                    None => p.total.is_some_and(|total| offset < total),
+                },
+                None => received >= CONNECTIONS_PAGE_LIMIT,
+            };
+            if received == 0 || !has_more {
+                break;
+            }
         }
 
-        let api_response: ApiConnectionsResponse = serde_json::from_str(&body).map_err(|e| {
-            Error::Unexpected(format!("Failed to parse connections: {} - {}", e, body))
-        })?;
-
-        let connections: Vec<BrokerConnection> = api_response
-            .connections
+        let connections: Vec<BrokerConnection> = api_connections
             .into_iter()
             .map(|c| {
                 // Use brokerage object if present, otherwise use top-level fields
@@ -452,6 +519,7 @@ impl BrokerApiClient for ConnectApiClient {
                     status: c.status,
                     disabled: c.disabled.unwrap_or(false),
                     disabled_date: None,
+                    disabled_reason: c.disabled_reason,
                     updated_at: c.updated_at,
                     name: c.name,
                 }
@@ -613,6 +681,9 @@ pub async fn fetch_subscription_plans_public(base_url: &str) -> Result<PlansResp
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
 
     #[test]
     fn test_client_creation() {
@@ -625,4 +696,133 @@ mod tests {
         let client = ConnectApiClient::new("https://api.wealthfolio.app/", "test-token").unwrap();
         assert_eq!(client.base_url, "https://api.wealthfolio.app");
     }
+
+    /// Serve one canned JSON body per incoming request, recording request lines.
+    fn spawn_mock_server(bodies: Vec<String>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let recorded = Arc::clone(&requests);
+
+        std::thread::spawn(move || {
+            for body in bodies {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.ends_with(b"\r\n\r\n") {
+                    let n = stream.read(&mut buf).unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let request_line = request.lines().next().unwrap_or_default().to_string();
+                recorded.lock().unwrap().push(request_line);
+
+                let response = format!(
+                    "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+
+        (base_url, requests)
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_follows_pagination() {
+        let (base_url, requests) = spawn_mock_server(vec![
+            r#"{"connections":[{"id":"c1"},{"id":"c2"}],"pagination":{"offset":0,"limit":2,"total":3,"has_more":true}}"#.to_string(),
+            r#"{"connections":[{"id":"c3"}],"pagination":{"offset":2,"limit":2,"total":3,"has_more":false}}"#.to_string(),
+        ]);
+        let client = ConnectApiClient::new(&base_url, "test-token").unwrap();
+
+        let connections = client.list_connections().await.unwrap();
+
+        let ids: Vec<&str> = connections.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["c1", "c2", "c3"]);
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("offset=0"));
+        assert!(requests[1].contains("offset=2"));
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_without_pagination_fetches_once() {
+        let (base_url, requests) =
+            spawn_mock_server(vec![r#"{"connections":[{"id":"c1"}]}"#.to_string()]);
+        let client = ConnectApiClient::new(&base_url, "test-token").unwrap();
+
+        let connections = client.list_connections().await.unwrap();
+
+        assert_eq!(connections.len(), 1);
+        assert_eq!(requests.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_without_pagination_follows_full_pages() {
+        let full_page: Vec<String> = (0..CONNECTIONS_PAGE_LIMIT)
+            .map(|i| format!(r#"{{"id":"c{}"}}"#, i))
+            .collect();
+        let (base_url, requests) = spawn_mock_server(vec![
+            format!(r#"{{"connections":[{}]}}"#, full_page.join(",")),
+            r#"{"connections":[{"id":"last"}]}"#.to_string(),
+        ]);
+        let client = ConnectApiClient::new(&base_url, "test-token").unwrap();
+
+        let connections = client.list_connections().await.unwrap();
+
+        assert_eq!(connections.len() as i64, CONNECTIONS_PAGE_LIMIT + 1);
+        assert_eq!(connections.last().unwrap().id, "last");
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].contains(&format!("offset={}", CONNECTIONS_PAGE_LIMIT)));
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_rejects_repeated_page() {
+        let page = r#"{"connections":[{"id":"c1"},{"id":"c2"}],"pagination":{"has_more":true}}"#;
+        let (base_url, requests) = spawn_mock_server(vec![page.to_string(), page.to_string()]);
+        let client = ConnectApiClient::new(&base_url, "test-token").unwrap();
+
+        let err = client.list_connections().await.unwrap_err();
+
+        assert!(err.to_string().contains("did not advance"));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_rejects_offset_that_does_not_advance() {
+        let (base_url, requests) = spawn_mock_server(vec![
+            r#"{"connections":[{"id":"c1"}],"pagination":{"offset":0,"has_more":true}}"#
+                .to_string(),
+            r#"{"connections":[{"id":"c2"}],"pagination":{"offset":0,"has_more":true}}"#
+                .to_string(),
+        ]);
+        let client = ConnectApiClient::new(&base_url, "test-token").unwrap();
+
+        let err = client.list_connections().await.unwrap_err();
+
+        assert!(err.to_string().contains("did not advance"));
+        assert_eq!(requests.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_list_connections_maps_disabled_reason() {
+        let (base_url, _) = spawn_mock_server(vec![
+            r#"{"connections":[{"id":"c1","disabled":true,"disabled_reason":"authorization expired"}]}"#.to_string(),
+        ]);
+        let client = ConnectApiClient::new(&base_url, "test-token").unwrap();
+
+        let connections = client.list_connections().await.unwrap();
+
+        assert!(connections[0].disabled);
+        assert_eq!(
+            connections[0].disabled_reason.as_deref(),
+            Some("authorization expired")
+        );
+    }
 }
@@ -16,8 +16,8 @@ pub use broker::{
     BrokerSyncService, BrokerSyncServiceTrait, NoOpProgressReporter, PaginatedUniversalActivity,
     PlanLimitValue, PlanLimits, PlanPricing, PlansResponse, PlatformRepositoryTrait,
     SubscriptionPlan, SyncAccountsResponse, SyncActivitiesResponse, SyncConfig,
-    SyncConnectionsResponse, SyncOrchestrator, SyncProgressPayload, SyncProgressReporter,
-    SyncResult, SyncStatus, UserInfo, UserTeam,
+    SyncConnectionsResponse, SyncEstimate, SyncMode, SyncOrchestrator, SyncProgressPayload,
+    SyncProgressReporter, SyncResult, SyncStatus, UserInfo, UserTeam,
 };
 
 // Re-export the HTTP client and public functions
@@ -1065,6 +1065,11 @@ pub struct BulkUpsertResult {
     pub updated: usize,
     /// Number of activities skipped (e.g., user-modified)
     pub skipped: usize,
+    /// (incoming ID, stored ID) of every activity created or updated. The
+    /// stored ID differs when the activity matched an existing one by
+    /// idempotency key.
+    #[serde(default)]
+    pub written_ids: Vec<(String, String)>,
 }
 
 /// Activity ready for persistence
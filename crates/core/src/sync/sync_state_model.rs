@@ -133,3 +133,27 @@ pub struct PlaidInvestmentsCheckpoint {
     /// Number of days to look back for updates
     pub lookback_days: u32,
 }
+
+/// Checkpoint for an in-progress broker activity sync (offset-based pagination).
+///
+/// Written after each persisted page and cleared when the sync succeeds, so a
+/// run that stopped part-way can resume at `pending_offset`. A chunked initial
+/// sync also records the date range of the chunks it has finished, so they are
+/// not fetched again.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BrokerActivitiesCheckpoint {
+    /// Start of the query window the offset belongs to (None = full history)
+    pub start_date: Option<String>,
+    /// End of the query window the offset belongs to (None = full history)
+    #[serde(default)]
+    pub end_date: Option<String>,
+    /// Offset of the next page to fetch
+    pub pending_offset: i64,
+    /// First day of the chunks already imported (inclusive)
+    #[serde(default)]
+    pub completed_start_date: Option<String>,
+    /// Last day of the chunks already imported (inclusive)
+    #[serde(default)]
+    pub completed_end_date: Option<String>,
+}
@@ -1091,6 +1091,7 @@ impl ActivityRepositoryTrait for ActivityRepository {
                                 } else {
                                     result.created += count;
                                 }
+                                result.written_ids.push((activity_id, activity_db.id));
                             }
                         }
                         Err(e) => {
@@ -1176,3 +1177,90 @@ impl ActivityRepositoryTrait for ActivityRepository {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{create_pool, run_migrations, write_actor::spawn_writer};
+    use tempfile::tempdir;
+
+    async fn create_test_repository() -> (ActivityRepository, tempfile::TempDir) {
+        let temp_dir = tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("test.db");
+        let db_path_str = db_path.to_string_lossy().to_string();
+
+        run_migrations(&db_path_str).expect("Failed to run migrations");
+        let pool = create_pool(&db_path_str).expect("Failed to create pool");
+        let writer = spawn_writer((*pool).clone());
+
+        // Activities reference an account
+        let mut conn = get_connection(&pool).expect("Failed to get connection");
+        diesel::sql_query(
+            "INSERT INTO accounts (id, name, account_type, currency, is_default, is_active, created_at, updated_at) \
+             VALUES ('acc', 'Test Account', 'REGULAR', 'USD', false, true, datetime('now'), datetime('now'))",
+        )
+        .execute(&mut conn)
+        .expect("Failed to create test account");
+
+        (ActivityRepository::new(pool, writer), temp_dir)
+    }
+
+    fn deposit(id: &str, idempotency_key: &str) -> ActivityUpsert {
+        ActivityUpsert {
+            id: id.to_string(),
+            account_id: "acc".to_string(),
+            asset_id: None,
+            activity_type: "DEPOSIT".to_string(),
+            subtype: None,
+            activity_date: "2024-01-15T00:00:00Z".to_string(),
+            quantity: None,
+            unit_price: None,
+            currency: "USD".to_string(),
+            fee: None,
+            amount: Some(Decimal::new(100, 0)),
+            status: None,
+            notes: None,
+            fx_rate: None,
+            metadata: None,
+            needs_review: None,
+            source_system: None,
+            source_record_id: None,
+            source_group_id: None,
+            idempotency_key: Some(idempotency_key.to_string()),
+            import_run_id: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bulk_upsert_reports_written_ids() {
+        let (repo, _dir) = create_test_repository().await;
+        repo.bulk_upsert(vec![deposit("a", "key-a"), deposit("b", "key-b")])
+            .await
+            .unwrap();
+        let mut conn = get_connection(&repo.pool).unwrap();
+        diesel::update(activities::table.filter(activities::id.eq("a")))
+            .set(activities::is_user_modified.eq(1))
+            .execute(&mut conn)
+            .unwrap();
+
+        let result = repo
+            .bulk_upsert(vec![
+                // User-modified: skipped
+                deposit("a", "key-a"),
+                // New provider ID for the existing "b"
+                deposit("b2", "key-b"),
+                deposit("c", "key-c"),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(result.skipped, 1);
+        assert_eq!(
+            result.written_ids,
+            vec![
+                ("b2".to_string(), "b".to_string()),
+                ("c".to_string(), "c".to_string()),
+            ]
+        );
+    }
+}
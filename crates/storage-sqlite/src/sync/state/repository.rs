@@ -4,6 +4,7 @@ use chrono::Utc;
 use diesel::prelude::*;
 use diesel::r2d2::{self, Pool};
 use diesel::sqlite::SqliteConnection;
+use serde_json::Value;
 use std::sync::Arc;
 
 use wealthfolio_core::errors::Result;
@@ -157,6 +158,62 @@ impl BrokerSyncStateRepository {
             .await
     }
 
+    /// Store (or clear, with `None`) the checkpoint of an in-progress sync
+    pub async fn upsert_checkpoint(
+        &self,
+        account_id: String,
+        provider: String,
+        checkpoint_json: Option<Value>,
+    ) -> Result<()> {
+        self.writer
+            .exec(move |conn| {
+                let now_str = Utc::now().to_rfc3339();
+                let checkpoint_str =
+                    checkpoint_json.map(|v| serde_json::to_string(&v).unwrap_or_default());
+
+                // Check if exists
+                let existing = brokers_sync_state::table
+                    .find((&account_id, &provider))
+                    .first::<BrokerSyncStateDB>(conn)
+                    .optional()
+                    .map_err(StorageError::from)?;
+
+                match existing {
+                    Some(_) => {
+                        diesel::update(brokers_sync_state::table.find((&account_id, &provider)))
+                            .set((
+                                brokers_sync_state::checkpoint_json.eq(&checkpoint_str),
+                                brokers_sync_state::updated_at.eq(&now_str),
+                            ))
+                            .execute(conn)
+                            .map_err(StorageError::from)?;
+                    }
+                    None => {
+                        let new_state = BrokerSyncStateDB {
+                            account_id,
+                            provider,
+                            checkpoint_json: checkpoint_str,
+                            last_attempted_at: None,
+                            last_successful_at: None,
+                            last_error: None,
+                            last_run_id: None,
+                            sync_status: "IDLE".to_string(),
+                            created_at: now_str.clone(),
+                            updated_at: now_str,
+                        };
+
+                        diesel::insert_into(brokers_sync_state::table)
+                            .values(&new_state)
+                            .execute(conn)
+                            .map_err(StorageError::from)?;
+                    }
+                }
+
+                Ok(())
+            })
+            .await
+    }
+
     /// Record a successful sync (upsert with IDLE status)
     pub async fn upsert_success(
         &self,
@@ -185,6 +242,7 @@ impl BrokerSyncStateRepository {
                                 brokers_sync_state::last_successful_at.eq(&now_str),
                                 brokers_sync_state::sync_status.eq("IDLE"),
                                 brokers_sync_state::last_error.eq::<Option<String>>(None),
+                                brokers_sync_state::checkpoint_json.eq::<Option<String>>(None),
                                 brokers_sync_state::last_run_id.eq(&import_run_id),
                                 brokers_sync_state::updated_at.eq(&now_str),
                             ))
@@ -315,3 +373,79 @@ impl BrokerSyncStateRepository {
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{create_pool, run_migrations, write_actor::spawn_writer};
+    use tempfile::tempdir;
+
+    async fn create_test_repository() -> (BrokerSyncStateRepository, tempfile::TempDir) {
+        let temp_dir = tempdir().expect("Failed to create temp directory");
+        let db_path = temp_dir.path().join("test.db");
+        let db_path_str = db_path.to_string_lossy().to_string();
+
+        run_migrations(&db_path_str).expect("Failed to run migrations");
+        let pool = create_pool(&db_path_str).expect("Failed to create pool");
+        let writer = spawn_writer((*pool).clone());
+
+        // Sync state rows reference an account
+        let mut conn = get_connection(&pool).expect("Failed to get connection");
+        diesel::sql_query(
+            "INSERT INTO accounts (id, name, account_type, currency, is_default, is_active, created_at, updated_at) \
+             VALUES ('acc', 'Test Account', 'REGULAR', 'USD', false, true, datetime('now'), datetime('now'))",
+        )
+        .execute(&mut conn)
+        .expect("Failed to create test account");
+
+        (BrokerSyncStateRepository::new(pool, writer), temp_dir)
+    }
+
+    #[tokio::test]
+    async fn test_upsert_checkpoint_stores_and_clears_checkpoint() {
+        let (repo, _dir) = create_test_repository().await;
+        let checkpoint = serde_json::json!({ "startDate": null, "pendingOffset": 200 });
+
+        repo.upsert_checkpoint(
+            "acc".to_string(),
+            "snaptrade".to_string(),
+            Some(checkpoint.clone()),
+        )
+        .await
+        .unwrap();
+        let state = repo.get("acc", "snaptrade").unwrap().unwrap();
+        assert_eq!(state.checkpoint_json, Some(checkpoint));
+        assert_eq!(state.last_successful_at, None);
+
+        repo.upsert_checkpoint("acc".to_string(), "snaptrade".to_string(), None)
+            .await
+            .unwrap();
+        let state = repo.get("acc", "snaptrade").unwrap().unwrap();
+        assert_eq!(state.checkpoint_json, None);
+    }
+
+    #[tokio::test]
+    async fn test_upsert_success_clears_checkpoint() {
+        let (repo, _dir) = create_test_repository().await;
+        repo.upsert_checkpoint(
+            "acc".to_string(),
+            "snaptrade".to_string(),
+            Some(serde_json::json!({ "pendingOffset": 100 })),
+        )
+        .await
+        .unwrap();
+
+        repo.upsert_success(
+            "acc".to_string(),
+            "snaptrade".to_string(),
+            "2024-06-30".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+
+        let state = repo.get("acc", "snaptrade").unwrap().unwrap();
+        assert_eq!(state.checkpoint_json, None);
+        assert!(state.last_successful_at.is_some());
+    }
+}